@@ -1,43 +1,791 @@
 #[macro_use]
 extern crate advent_of_code;
 
-use std::env;
+use std::collections::hash_map::RandomState;
+use std::collections::BTreeMap;
+use std::hash::{BuildHasher, Hasher};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::{fs, process, thread};
 
+use advent_of_code::utils::challenges::calendar::{self, PartStatus, YearProgress};
+use advent_of_code::utils::challenges::html_report::{self, RunResult};
+use advent_of_code::utils::challenges::leaderboard::{Leaderboard, MIN_REFRESH};
 use advent_of_code::utils::challenges::prelude::*;
+use advent_of_code::utils::challenges::readme::{self, DayProgress};
+use advent_of_code::utils::challenges::{
+	challenge_header, inputs, manifest_path, parse_profile, scaffold, solve_in_order, unlock, watch, AocClient,
+	ChallengeError, Config,
+};
+use advent_of_code::utils::grids::{write_gif, Image};
+use advent_of_code::utils::{debug, visualize};
+use chrono::Utc;
+use clap::error::ErrorKind;
+use clap::{Args, CommandFactory, Parser, Subcommand};
+use tracing::Level;
+use tracing_subscriber::fmt::format::FmtSpan;
 
 preload_challenges!(into CHALLENGES);
+
+#[cfg(feature = "memory")]
+#[global_allocator]
+static ALLOCATOR: advent_of_code::utils::challenges::memory::CountingAllocator =
+	advent_of_code::utils::challenges::memory::CountingAllocator;
+
 fn is_year(nr: &u16) -> bool {
-	nr >= &2015 && nr <= &2023
+	unlock::valid_years(Utc::now()).contains(nr)
 }
 
 fn is_day(nr: &u8) -> bool {
-	nr >= &1 && nr <= &25
+	(1..=25).contains(nr)
 }
 
-fn main() {
-	let challenges = &*CHALLENGES;
-	let year: u16 = env::args()
-		.nth(1)
-		.expect("First parameter must be the event year!")
-		.parse()
-		.expect("Could not parse year to unsigned integer");
-	let day: u8 = env::args()
-		.nth(2)
-		.expect("Second parameter must be the day of the month!")
-		.parse()
-		.expect("Could not parse year to unsigned integer");
+/// Days are parsed before the year is known, events with fewer days are checked afterwards
+fn check_day(year: u16, day: Option<u8>) {
+	if let Some(day) = day.filter(|day| *day > unlock::days_in_event(year)) {
+		Cli::command()
+			.error(
+				ErrorKind::ValueValidation,
+				format!(
+					"Wrong day ({}) supplied, {} has {} days",
+					day,
+					year,
+					unlock::days_in_event(year)
+				),
+			)
+			.exit();
+	}
+}
+
+/// Values of `--arg` that don't fit the type a solution declares are reported as a usage error, before solving anything
+fn validate_puzzle_args(year: Option<u16>, day: Option<u8>, config: &Config) {
+	let mut args = config.puzzle_args.clone();
+	args.input = config.input;
+
+	let challenges = CHALLENGES
+		.iter()
+		.filter(|c| year.is_none_or(|year| c.year == year))
+		.filter(|c| day.is_none_or(|day| c.day == day));
+	for challenge in challenges {
+		if let Err(error) = challenge.solver.check_args(&args) {
+			Cli::command()
+				.error(
+					ErrorKind::ValueValidation,
+					format!("{} (declared by {} · Day {})", error, challenge.year, challenge.day),
+				)
+				.exit();
+		}
+	}
+}
 
+fn parse_year(value: &str) -> Result<u16, String> {
+	let year: u16 = value
+		.parse()
+		.map_err(|_| "Could not parse year to unsigned integer".to_string())?;
 	if !is_year(&year) {
-		panic!("Wrong year ({}) supplied", year)
+		return Err(format!("Wrong year ({}) supplied", year));
 	}
+	Ok(year)
+}
 
+fn parse_day(value: &str) -> Result<u8, String> {
+	let day: u8 = value
+		.parse()
+		.map_err(|_| "Could not parse day to unsigned integer".to_string())?;
 	if !is_day(&day) {
-		panic!("Wrong day ({}) supplied", day)
+		return Err(format!("Wrong day ({}) supplied", day));
 	}
+	Ok(day)
+}
+
+#[derive(Debug, Args)]
+struct RunArgs {
+	/// Event year, all years are run when omitted
+	#[arg(value_parser = parse_year)]
+	year: Option<u16>,
+
+	/// Day of the month, the whole year is run when omitted
+	#[arg(value_parser = parse_day, requires = "year")]
+	day: Option<u8>,
+
+	/// Number of challenges to run in parallel, 0 uses all CPU cores
+	#[arg(short, long, default_value_t = 1)]
+	jobs: usize,
+
+	/// Runs every challenge against the inputs of all profiles, to catch solutions that only work on one input
+	#[arg(long, conflicts_with = "answers")]
+	all_profiles: bool,
+
+	/// Writes a self-contained HTML report of the run to a file
+	#[arg(long, value_name = "FILE")]
+	html: Option<PathBuf>,
+
+	/// Hides the answers in the HTML report, so it can be shared without spoilers
+	#[arg(long, requires = "html")]
+	mask_answers: bool,
+
+	/// Plays the frames recorded by the solution of the day in the terminal
+	#[arg(long, requires = "day", conflicts_with = "all_profiles")]
+	visualize: bool,
+
+	/// Speed of the visualization in frames per second
+	#[arg(long, default_value_t = 20.0, requires = "visualize")]
+	fps: f64,
+
+	/// Writes the frames to an animated GIF instead of playing them
+	#[arg(long, value_name = "FILE", requires = "visualize")]
+	gif: Option<PathBuf>,
+
+	/// Writes the answers to a file, used by watch mode to compare runs
+	#[arg(long, hide = true)]
+	answers: Option<PathBuf>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+	/// Shows per year which days have a solution, which parts are unsolved and which answers are verified, as they were
+	/// last run
+	Calendar {
+		/// Event year, all years with solutions are shown when omitted
+		#[arg(value_parser = parse_year)]
+		year: Option<u16>,
+
+		/// Compares the solved parts with the stars of the account on the website
+		#[arg(long)]
+		stars: bool,
+	},
+
+	/// Stores an encrypted copy of the plain inputs of a year, using the AOC_INPUT_KEY from .env
+	Encrypt {
+		#[arg(value_parser = parse_year)]
+		year: u16,
+	},
+
+	/// Downloads the puzzle inputs of a year (or a single day) that are not stored yet
+	Fetch {
+		#[arg(value_parser = parse_year)]
+		year: u16,
+		#[arg(value_parser = parse_day)]
+		day: Option<u8>,
+	},
+
+	/// Shows a private leaderboard, which is refreshed at most once every 15 minutes
+	Leaderboard {
+		/// Id of the leaderboard, the number in its URL
+		id: u64,
+
+		/// Event year, the most recent event when omitted
+		#[arg(long, value_parser = parse_year)]
+		year: Option<u16>,
+
+		/// Day to show the completion times of, the most recent day with stars when omitted
+		#[arg(long, value_parser = parse_day)]
+		day: Option<u8>,
+	},
+
+	/// Runs all solutions and regenerates the progress section of the README
+	Readme {
+		/// Number of challenges to run in parallel, 0 uses all CPU cores (which makes the runtimes less reliable)
+		#[arg(short, long, default_value_t = 1)]
+		jobs: usize,
+
+		/// Number of times every challenge is run, the median runtime is reported
+		#[arg(long, default_value_t = 5)]
+		runs: usize,
+	},
+
+	/// Checks whether the session cookie of the selected profile is still accepted by the website
+	Session,
+
+	/// Counts down to the unlock of a puzzle, then downloads it, scaffolds the solution and starts watch mode
+	Wait {
+		#[arg(value_parser = parse_year)]
+		year: u16,
+		#[arg(value_parser = parse_day)]
+		day: u8,
+	},
+
+	/// Rebuilds and reruns a day whenever its solution, input or example files change
+	Watch {
+		#[arg(value_parser = parse_year)]
+		year: u16,
+		#[arg(value_parser = parse_day)]
+		day: u8,
+	},
+}
 
-	if let Some(challenge) = challenges.iter().find(|c| c.day == day && c.year == year) {
-		challenge.run();
+#[derive(Debug, Parser)]
+#[command(about = "Runs Advent of Code solutions", args_conflicts_with_subcommands = true)]
+struct Cli {
+	#[command(subcommand)]
+	command: Option<Command>,
+
+	#[command(flatten)]
+	run: RunArgs,
+
+	/// Never download anything, missing inputs are reported instead (or set AOC_OFFLINE=1)
+	#[arg(long, global = true)]
+	offline: bool,
+
+	/// Directory holding the puzzle inputs, relative to the crate root (or set AOC_PUZZLE_ROOT)
+	#[arg(long, global = true, value_name = "DIR")]
+	puzzle_root: Option<PathBuf>,
+
+	/// Solves the example input `<day>.example.txt` instead of the real input
+	#[arg(long, global = true)]
+	example: bool,
+
+	/// Argument handed to the solutions, e.g. `--arg size=(101,103)`, can be repeated
+	#[arg(long = "arg", global = true, value_name = "NAME=VALUE", value_parser = parse_puzzle_arg)]
+	puzzle_args: Vec<(String, PuzzleArg)>,
+
+	/// Uses the inputs and AOC_SESSION_<PROFILE> of another account (or set AOC_PROFILE)
+	#[arg(long, global = true, value_name = "NAME", value_parser = parse_profile)]
+	profile: Option<String>,
+
+	/// Prints the `p!` statements up to a level, e.g. `--debug=2` (or set AOC_DEBUG)
+	#[arg(
+		long,
+		global = true,
+		value_name = "LEVEL",
+		num_args = 0..=1,
+		require_equals = true,
+		default_missing_value = "1"
+	)]
+	debug: Option<u8>,
+
+	/// Logs the challenges and parts, with the output of `p!` as events, up to a level, e.g. `--trace=info`
+	#[arg(
+		long,
+		global = true,
+		value_name = "LEVEL",
+		num_args = 0..=1,
+		require_equals = true,
+		default_missing_value = "debug"
+	)]
+	trace: Option<Level>,
+
+	/// Writes the trace to a file instead of stderr
+	#[arg(long, global = true, value_name = "FILE", requires = "trace")]
+	trace_file: Option<PathBuf>,
+}
+
+fn parse_puzzle_arg(value: &str) -> Result<(String, PuzzleArg), String> {
+	match value.split_once('=') {
+		Some((name, value)) if !name.trim().is_empty() => Ok((name.trim().to_string(), PuzzleArg::parse(value))),
+		_ => Err(format!("Expected NAME=VALUE ({})", value)),
+	}
+}
+
+/// Sends spans and events to stderr or a file, the time spent in every challenge and part is logged when it closes
+fn init_tracing(level: Level, file: Option<&PathBuf>) {
+	let subscriber = tracing_subscriber::fmt()
+		.with_max_level(level)
+		.with_target(false)
+		.with_span_events(FmtSpan::CLOSE);
+
+	match file {
+		Some(path) => {
+			let file = fs::File::create(path).unwrap_or_else(|source| {
+				exit_with_error(&ChallengeError::Io {
+					path: path.clone(),
+					source,
+				})
+			});
+			subscriber.with_ansi(false).with_writer(Mutex::new(file)).init();
+		}
+		None => subscriber.with_writer(io::stderr).init(),
+	}
+}
+
+fn find_challenge(year: u16, day: u8) -> &'static Challenge {
+	match CHALLENGES.iter().find(|c| c.day == day && c.year == year) {
+		Some(challenge) => challenge,
+		None => panic!("Could not find year {} day {}", year, day),
+	}
+}
+
+fn print_error(year: u16, day: u8, profile: Option<&str>, error: &ChallengeError) {
+	println!("{}", challenge_header(year, day, profile).cyan());
+	println!(" => {}: {}", "Error".normal(), error.to_string().red());
+	println!();
+}
+
+fn resolve_jobs(jobs: usize) -> usize {
+	match jobs {
+		0 => thread::available_parallelism().map_or(1, |cores| cores.get()),
+		jobs => jobs,
+	}
+}
+
+fn write_visualization(frames: &[visualize::Frame], args: &RunArgs) {
+	let Some(path) = &args.gif else {
+		visualize::play(frames, args.fps);
+		return;
+	};
+	if frames.is_empty() {
+		println!("{}", "The solution did not record any frames".yellow());
+		return;
+	}
+
+	let images: Vec<Image> = frames
+		.iter()
+		.map(|frame| frame.to_image(4, visualize::cell_color))
+		.collect();
+	match write_gif(path, &images, Duration::from_secs_f64(1.0 / args.fps.max(0.1))) {
+		Ok(()) => println!("{} {}", "Animation written to".bright_black(), path.display()),
+		Err(source) => exit_with_error(&ChallengeError::Io {
+			path: path.clone(),
+			source,
+		}),
+	}
+}
+
+fn run(args: RunArgs, config: &Config) {
+	let challenges: Vec<&Challenge> = CHALLENGES
+		.iter()
+		.filter(|c| args.year.is_none_or(|year| c.year == year))
+		.filter(|c| args.day.is_none_or(|day| c.day == day))
+		.collect();
+
+	if challenges.is_empty() {
+		match (args.year, args.day) {
+			(Some(year), Some(day)) => panic!("Could not find year {} day {}", year, day),
+			(Some(year), None) => panic!("Could not find any challenges for year {}", year),
+			_ => panic!("Could not find any challenges"),
+		}
+	}
+
+	let jobs = resolve_jobs(args.jobs);
+
+	let configs: Vec<Config> = if args.all_profiles {
+		let profiles = config.profiles();
+		if profiles.is_empty() {
+			eprintln!(
+				"{}",
+				"No profiles found, add an AOC_SESSION(_<PROFILE>) or a <profile> input directory".red()
+			);
+			process::exit(1);
+		}
+		profiles
+			.iter()
+			.map(|profile| config.with_profile(profile.as_deref()))
+			.collect()
 	} else {
-		panic!("Could not find year {} day {}", year, day);
+		vec![config.clone()]
+	};
+	let runs: Vec<(&Challenge, &Config)> = challenges
+		.iter()
+		.flat_map(|challenge| configs.iter().map(move |config| (*challenge, config)))
+		.collect();
+
+	let mut missing_inputs = Vec::new();
+	let mut results = Vec::new();
+	let mut failed_profiles: Vec<(&Challenge, Vec<&str>)> = Vec::new();
+	let mut statuses: BTreeMap<u16, YearProgress> = BTreeMap::new();
+	let mut failed = false;
+	if args.visualize {
+		visualize::enable();
+	}
+	solve_in_order(&runs, jobs, |challenge, run_config, report| {
+		let profile = run_config.profile.as_deref();
+		if run_config.profile == config.profile {
+			statuses
+				.entry(challenge.year)
+				.or_default()
+				.insert(challenge.day, PartStatus::from_result(&report));
+		}
+		if args.visualize {
+			write_visualization(&visualize::take(), &args);
+		}
+		match report {
+			Ok(mut report) => {
+				// Expected answers belong to the input of the selected profile
+				if run_config.profile != config.profile {
+					report.parts.iter_mut().for_each(|part| part.expected = None);
+				}
+				report.print();
+				// Competing implementations that give different answers can't both be right
+				failed |= !report.parts.iter().all(|part| part.variants_agree());
+				if let Some(path) = &args.answers {
+					watch::write_answers(&report, path);
+				}
+				results.push(RunResult {
+					year: challenge.year,
+					day: challenge.day,
+					profile: profile.map(String::from),
+					result: Ok(report),
+				});
+			}
+			Err(error) => {
+				print_error(challenge.year, challenge.day, profile, &error);
+				if let ChallengeError::MissingInput(path) = &error {
+					missing_inputs.push(path.clone());
+				} else if args.all_profiles {
+					let profile = profile.unwrap_or("default");
+					match failed_profiles.last_mut() {
+						Some((last, profiles)) if std::ptr::eq(*last, challenge) => profiles.push(profile),
+						_ => failed_profiles.push((challenge, vec![profile])),
+					}
+				}
+				results.push(RunResult {
+					year: challenge.year,
+					day: challenge.day,
+					profile: profile.map(String::from),
+					result: Err(error),
+				});
+				failed = true;
+			}
+		}
+	});
+
+	// Runs against the example input say nothing about the progress on the puzzle
+	if config.input == InputKind::Real {
+		save_progress(&statuses, config);
+	}
+
+	if let Some(path) = &args.html {
+		let html = html_report::render(&results, args.mask_answers);
+		fs::write(path, html).unwrap_or_else(|source| {
+			exit_with_error(&ChallengeError::Io {
+				path: path.clone(),
+				source,
+			})
+		});
+		println!("{} {}", "HTML report written to".bright_black(), path.display());
+		println!();
+	}
+
+	if args.all_profiles && !failed_profiles.is_empty() {
+		println!("{}", "Failed for some profiles".yellow());
+		for (challenge, profiles) in &failed_profiles {
+			println!(
+				" => {} · Day {}: {}",
+				challenge.year,
+				challenge.day,
+				profiles.join(", ")
+			);
+		}
+		println!();
+	}
+
+	if !missing_inputs.is_empty() {
+		println!("{}", "Missing puzzle inputs".yellow());
+		for path in &missing_inputs {
+			println!(" => {}", path.display());
+		}
+		println!("Download them with `runner fetch <year>` when online");
+	}
+
+	if failed {
+		process::exit(1);
+	}
+}
+
+fn fetch(year: u16, day: Option<u8>, config: &Config) {
+	if config.offline {
+		eprintln!("{}", "Fetching inputs is not possible in offline mode".red());
+		process::exit(1);
+	}
+
+	let mut failed = false;
+	for day in day.map_or(1..=unlock::days_in_event(year), |day| day..=day) {
+		let path = inputs::storage_path(config, year, day);
+		if inputs::is_stored(config, year, day) {
+			println!(" => {} {}", path.display(), "(already downloaded)".bright_black());
+			continue;
+		}
+
+		match inputs::download_puzzle(config, year, day) {
+			Ok(_) => println!(" => {}", path.display().to_string().green()),
+			Err(error @ ChallengeError::Locked { .. }) => {
+				// Later days unlock even later
+				println!(" => {}", error.to_string().yellow());
+				break;
+			}
+			Err(error) => {
+				println!(" => {}", error.to_string().red());
+				failed = true;
+			}
+		}
+	}
+
+	if failed {
+		process::exit(1);
+	}
+}
+
+fn session(config: &Config) {
+	if config.offline {
+		eprintln!("{}", "Checking the session is not possible in offline mode".red());
+		process::exit(1);
+	}
+
+	match AocClient::from_config(config).and_then(|client| client.validate()) {
+		Ok(()) => println!(" => {}", format!("{} is valid", config.session_var()).green()),
+		Err(error) => {
+			println!(" => {}", error.to_string().red());
+			process::exit(1);
+		}
+	}
+}
+
+/// Keeps the statuses of a run for the calendar, a failure to store them doesn't fail the run
+fn save_progress(statuses: &BTreeMap<u16, YearProgress>, config: &Config) {
+	if let Err(error) = calendar::save_progress(config, statuses) {
+		eprintln!("{}", error.to_string().red());
+	}
+}
+
+fn calendar(year: Option<u16>, stars: bool, config: &Config) {
+	if stars && config.offline {
+		eprintln!("{}", "Comparing stars is not possible in offline mode".red());
+		process::exit(1);
+	}
+	let client = stars.then(|| AocClient::from_config(config).unwrap_or_else(|error| exit_with_error(&error)));
+
+	// Days are shown as they were last run, only the presence of their input is checked
+	let stored = calendar::load_progress(config);
+	let mut progress: BTreeMap<u16, YearProgress> = BTreeMap::new();
+	if let Some(year) = year {
+		progress.insert(year, YearProgress::new());
+	}
+	for challenge in CHALLENGES.iter().filter(|c| year.is_none_or(|year| c.year == year)) {
+		let parts = match stored.get(&challenge.year).and_then(|days| days.get(&challenge.day)) {
+			Some(parts) => parts.clone(),
+			None if inputs::is_stored(config, challenge.year, challenge.day) => vec![PartStatus::NotRun; 2],
+			None => vec![PartStatus::Failed; 2],
+		};
+		progress.entry(challenge.year).or_default().insert(challenge.day, parts);
+	}
+
+	for (year, days) in &progress {
+		calendar::print_year(*year, days);
+		match client.as_ref().map(|client| client.stars(*year)) {
+			Some(Ok(stars)) => calendar::print_star_comparison(days, &stars),
+			Some(Err(error)) => println!("  {}", error.to_string().red()),
+			None => {}
+		}
+		println!();
+	}
+	calendar::print_legend();
+}
+
+fn leaderboard(id: u64, year: Option<u16>, day: Option<u8>, config: &Config) {
+	let year = year.unwrap_or_else(|| unlock::latest_year(Utc::now()));
+	let (leaderboard, age) = Leaderboard::load(config, year, id).unwrap_or_else(|error| exit_with_error(&error));
+
+	leaderboard.print(id);
+	if let Some(day) = day.or_else(|| leaderboard.latest_day()) {
+		leaderboard.print_day(day);
+	}
+
+	let refresh = MIN_REFRESH.saturating_sub(age).as_secs().div_ceil(60);
+	println!(
+		"{}",
+		format!(
+			"Updated {} minute(s) ago, refreshes in {} minute(s) at the earliest",
+			age.as_secs() / 60,
+			refresh
+		)
+		.bright_black()
+	);
+}
+
+fn readme(jobs: usize, runs: usize, config: &Config) {
+	let challenges: Vec<(&Challenge, &Config)> = CHALLENGES.iter().map(|challenge| (challenge, config)).collect();
+
+	let mut years: BTreeMap<u16, BTreeMap<u8, DayProgress>> = BTreeMap::new();
+	for run in 0..runs.max(1) {
+		solve_in_order(&challenges, resolve_jobs(jobs), |challenge, _, result| {
+			let progress = years
+				.entry(challenge.year)
+				.or_default()
+				.entry(challenge.day)
+				.or_default();
+			progress.parts = PartStatus::from_result(&result);
+			match result {
+				Ok(report) => {
+					progress.runtimes.push(report.duration());
+					progress.title = report.title;
+				}
+				Err(error) if run == 0 => print_error(challenge.year, challenge.day, None, &error),
+				Err(_) => {}
+			}
+		});
+	}
+	let statuses = years
+		.iter()
+		.map(|(year, days)| {
+			(
+				*year,
+				days.iter()
+					.map(|(day, progress)| (*day, progress.parts.clone()))
+					.collect(),
+			)
+		})
+		.collect();
+	save_progress(&statuses, config);
+
+	let path = manifest_path(readme::README);
+	let contents = fs::read_to_string(&path).unwrap_or_else(|source| {
+		exit_with_error(&ChallengeError::Io {
+			path: path.clone(),
+			source,
+		})
+	});
+	let Some(contents) = readme::replace_section(&contents, &readme::progress_tables(&years)) else {
+		eprintln!(
+			"{}",
+			format!(
+				"Add {} and {} to {} to mark where the progress goes",
+				readme::START_MARKER,
+				readme::END_MARKER,
+				readme::README
+			)
+			.red()
+		);
+		process::exit(1);
+	};
+	fs::write(&path, contents).unwrap_or_else(|source| {
+		exit_with_error(&ChallengeError::Io {
+			path: path.clone(),
+			source,
+		})
+	});
+	println!(" => {}", path.display().to_string().green());
+}
+
+/// A random delay of one to five seconds, so not everyone waiting for the unlock hits the server at the same moment
+fn politeness_delay() -> Duration {
+	let random = RandomState::new().build_hasher().finish();
+	Duration::from_millis(1000 + random % 4000)
+}
+
+fn exit_with_error(error: &ChallengeError) -> ! {
+	println!(" => {}", error.to_string().red());
+	process::exit(1);
+}
+
+fn wait(year: u16, day: u8, config: &Config) -> ! {
+	if config.offline {
+		eprintln!("{}", "Waiting for a puzzle is not possible in offline mode".red());
+		process::exit(1);
+	}
+
+	// An expired session is better found out now than at the moment the puzzle unlocks
+	if let Err(error) = AocClient::from_config(config).and_then(|client| client.validate()) {
+		exit_with_error(&error);
+	}
+
+	println!("{}", format!("{} · Day {}", year, day).cyan());
+	while let Some(remaining) = unlock::time_until_unlock(year, day, Utc::now()) {
+		print!("\r => Unlocks in {} ", unlock::format_remaining(&remaining).yellow());
+		io::stdout().flush().ok();
+		thread::sleep(remaining.min(Duration::from_secs(1)));
+	}
+	println!("\r => {}{}", "Unlocked!".green(), " ".repeat(24));
+	thread::sleep(politeness_delay());
+
+	if !inputs::is_stored(config, year, day) {
+		match inputs::download_puzzle(config, year, day) {
+			Ok(_) => println!(
+				" => {}",
+				inputs::storage_path(config, year, day).display().to_string().green()
+			),
+			Err(error) => exit_with_error(&error),
+		}
+	}
+	match inputs::download_description(config, year, day) {
+		Ok(path) => println!(" => {}", path.display().to_string().green()),
+		Err(error) => println!(" => {}", error.to_string().yellow()),
+	}
+
+	match scaffold::create_solution(year, day) {
+		Ok(Some(path)) => println!(" => {}", path.display().to_string().green()),
+		Ok(None) => {}
+		Err(error) => exit_with_error(&error),
+	}
+	match scaffold::register_challenge(year, day) {
+		Ok(true) => println!(" => {}", "Registered in preload_challenges!".green()),
+		Ok(false) => {}
+		Err(error) => exit_with_error(&error),
+	}
+	println!();
+
+	watch::watch(year, day, config)
+}
+
+fn encrypt(year: u16, config: &Config) {
+	match inputs::encrypt_inputs(config, year) {
+		Ok(paths) if paths.is_empty() => println!("{}", "No plain inputs left to encrypt".bright_black()),
+		Ok(paths) => {
+			for path in paths {
+				println!(" => {}", path.display().to_string().green());
+			}
+		}
+		Err(error) => {
+			eprintln!("{}", error.to_string().red());
+			process::exit(1);
+		}
+	}
+}
+
+fn main() {
+	let cli = Cli::parse();
+	if let Some(level) = cli.trace {
+		init_tracing(level, cli.trace_file.as_ref());
+	}
+	let mut config = Config::from_env().unwrap_or_else(|error| exit_with_error(&error));
+	config.offline |= cli.offline;
+	if let Some(puzzle_root) = cli.puzzle_root {
+		config.puzzle_root = manifest_path(puzzle_root);
+	}
+	if cli.profile.is_some() {
+		config.profile = cli.profile;
+	}
+	config.puzzle_args.extend(cli.puzzle_args);
+	if cli.example {
+		config.input = InputKind::Example;
+	}
+	if let Some(debug) = cli.debug {
+		config.debug = debug;
+	}
+	debug::set_verbosity(config.debug);
+
+	match &cli.command {
+		Some(Command::Fetch { year, day }) => check_day(*year, *day),
+		Some(Command::Wait { year, day }) | Some(Command::Watch { year, day }) => check_day(*year, Some(*day)),
+		Some(Command::Leaderboard {
+			year: Some(year), day, ..
+		}) => check_day(*year, *day),
+		_ => cli.run.year.map_or((), |year| check_day(year, cli.run.day)),
+	}
+
+	match &cli.command {
+		Some(Command::Calendar { year, .. }) => validate_puzzle_args(*year, None, &config),
+		Some(Command::Readme { .. }) => validate_puzzle_args(None, None, &config),
+		Some(Command::Wait { year, day }) | Some(Command::Watch { year, day }) => {
+			validate_puzzle_args(Some(*year), Some(*day), &config)
+		}
+		None => validate_puzzle_args(cli.run.year, cli.run.day, &config),
+		_ => {}
+	}
+
+	match cli.command {
+		Some(Command::Calendar { year, stars }) => calendar(year, stars, &config),
+		Some(Command::Encrypt { year }) => encrypt(year, &config),
+		Some(Command::Fetch { year, day }) => fetch(year, day, &config),
+		Some(Command::Leaderboard { id, year, day }) => leaderboard(id, year, day, &config),
+		Some(Command::Readme { jobs, runs }) => readme(jobs, runs, &config),
+		Some(Command::Session) => session(&config),
+		Some(Command::Wait { year, day }) => wait(year, day, &config),
+		Some(Command::Watch { year, day }) => {
+			find_challenge(year, day);
+			watch::watch(year, day, &config)
+		}
+		None => run(cli.run, &config),
 	}
 }
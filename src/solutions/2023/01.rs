@@ -1,9 +1,7 @@
-use std::str::Lines;
-
 use advent_of_code::utils::challenges::prelude::*;
 
-fn parse(input: &PuzzleInput) -> Lines {
-	input.trim().lines()
+fn parse(input: &PuzzleInput) -> Vec<String> {
+	input.trim().lines().map(String::from).collect()
 }
 
 fn find_first_digit(s: &str, reverse: bool) -> Option<u64> {
@@ -38,8 +36,8 @@ fn find_first_digit_or_written_number(s: &str, reverse: bool) -> Option<u64> {
 	if reverse {
 		let mut mut_line = s;
 		while !mut_line.is_empty() {
-			if mut_line.chars().rev().next()?.is_numeric() {
-				return Some(mut_line.chars().rev().next()?.to_digit(10).unwrap() as u64);
+			if mut_line.chars().next_back()?.is_numeric() {
+				return Some(mut_line.chars().next_back()?.to_digit(10).unwrap() as u64);
 			}
 			for (string_number, number) in get_numbers() {
 				if mut_line.ends_with(string_number) {
@@ -66,8 +64,9 @@ fn find_first_digit_or_written_number(s: &str, reverse: bool) -> Option<u64> {
 	None
 }
 
-fn part_one(input: &PuzzleInput, _args: &RawPuzzleArgs) -> Solution {
-	let lines = parse(input)
+fn part_one(input: &[String], _args: &RawPuzzleArgs) -> Solution {
+	let lines = input
+		.iter()
 		.map(|line| {
 			let first_digit = find_first_digit(line, false).unwrap();
 			let last_digit = find_first_digit(line, true).unwrap();
@@ -79,8 +78,9 @@ fn part_one(input: &PuzzleInput, _args: &RawPuzzleArgs) -> Solution {
 	Answer(lines)
 }
 
-fn part_two(input: &PuzzleInput, _args: &RawPuzzleArgs) -> Solution {
-	let lines = parse(input)
+fn part_two(input: &[String], _args: &RawPuzzleArgs) -> Solution {
+	let lines = input
+		.iter()
 		.map(|line| {
 			let first_digit = find_first_digit_or_written_number(line, false).unwrap();
 			let last_digit = find_first_digit_or_written_number(line, true).unwrap();
@@ -90,4 +90,4 @@ fn part_two(input: &PuzzleInput, _args: &RawPuzzleArgs) -> Solution {
 	Answer(lines)
 }
 
-solve!(part_one, part_two);
+solve!(parse => part_one, part_two);
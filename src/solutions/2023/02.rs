@@ -99,15 +99,18 @@ fn parse(input: &PuzzleInput) -> Vec<Game> {
 		.collect()
 }
 
-fn part_one(input: &PuzzleInput, _args: &RawPuzzleArgs) -> Solution {
-	let games = parse(input);
-	let possible = games.iter().filter(|game| game.is_playable(12, 13, 14));
+puzzle_args!(max_red: u64 = 12, max_green: u64 = 13, max_blue: u64 = 14);
+
+fn part_one(games: &[Game], args: &RawPuzzleArgs) -> Solution {
+	let args = PuzzleArgs::from(args);
+	let possible = games
+		.iter()
+		.filter(|game| game.is_playable(args.max_red, args.max_green, args.max_blue));
 	Answer(possible.map(|game| game.id).sum())
 }
 
-fn part_two(input: &PuzzleInput, _args: &RawPuzzleArgs) -> Solution {
-	let games = parse(input);
+fn part_two(games: &[Game], _args: &RawPuzzleArgs) -> Solution {
 	Answer(games.iter().map(|game| game.power()).sum())
 }
 
-solve!(part_one, part_two);
+solve!(parse => part_one, part_two);
@@ -1,14 +1,15 @@
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::fmt;
-use std::ops::{Deref, DerefMut};
+use std::ops::Deref;
 
 use advent_of_code::utils::challenges::prelude::*;
-use advent_of_code::utils::grids::{Grid, GridPoint};
+use advent_of_code::utils::grids::{DenseGrid, GridAccess, GridPoint};
 
 #[derive(Debug, Default)]
 enum SchematicElement {
 	Number(u32),
-	Gear(Vec<u32>),
+	Gear,
 	Symbol,
 	#[default]
 	Empty,
@@ -18,7 +19,7 @@ impl fmt::Display for SchematicElement {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 		let char = match self {
 			Self::Number(nr) => char::from_digit(*nr, 10).unwrap(),
-			Self::Gear(_) => '*',
+			Self::Gear => '*',
 			Self::Empty => '.',
 			Self::Symbol => '_',
 		};
@@ -28,19 +29,22 @@ impl fmt::Display for SchematicElement {
 }
 
 #[derive(Debug, Default)]
-struct EngineSchematic {
-	grid: Grid<isize, isize, SchematicElement>,
+struct EngineSchematic<G> {
+	grid: G,
 }
 
-impl From<&str> for EngineSchematic {
+impl<G: GridAccess<isize, isize, SchematicElement>> From<&str> for EngineSchematic<G> {
 	fn from(schematic: &str) -> Self {
-		let mut engine_schematic = Self { ..Self::default() };
+		let width = schematic.lines().map(str::len).max().unwrap_or(0);
+		let mut engine_schematic = Self {
+			grid: G::with_size(schematic.lines().count(), width),
+		};
 		for (y, line) in schematic.lines().enumerate() {
 			for (x, char) in line.chars().enumerate() {
 				let point = match char {
 					'0'..='9' => SchematicElement::Number(char.to_digit(10).unwrap()),
 					'.' => SchematicElement::Empty,
-					'*' => SchematicElement::Gear(vec![]),
+					'*' => SchematicElement::Gear,
 					_ => SchematicElement::Symbol,
 				};
 				engine_schematic.grid.set((y as isize, x as isize), RefCell::new(point))
@@ -56,6 +60,7 @@ struct Result {
 	gear_point: Option<GridPoint<isize, isize>>,
 	number: u32,
 	numbers: Vec<u32>,
+	gears: HashMap<GridPoint<isize, isize>, Vec<u32>>,
 }
 
 impl Result {
@@ -65,6 +70,7 @@ impl Result {
 			gear_point: None,
 			number: 0,
 			numbers: vec![],
+			gears: HashMap::new(),
 		}
 	}
 
@@ -73,8 +79,11 @@ impl Result {
 	}
 
 	fn add_part_if_part_number(&mut self) {
+		if let Some(point) = self.gear_point {
+			self.gears.entry(point).or_default().push(self.number);
+		}
 		if self.is_part_number {
-			self.numbers.push(self.number.clone());
+			self.numbers.push(self.number);
 		}
 
 		self.reset();
@@ -89,9 +98,20 @@ impl Result {
 	fn total(&self) -> u64 {
 		self.numbers.iter().sum::<u32>() as u64
 	}
+
+	fn gear_ratios(&self) -> u64 {
+		self.gears
+			.values()
+			.filter(|numbers| numbers.len() >= 2)
+			.map(|numbers| numbers.iter().product::<u32>() as u64)
+			.sum()
+	}
 }
 
-fn run_schematics(schematics: &mut EngineSchematic, result: &mut Result) {
+fn run_schematics<G: GridAccess<isize, isize, SchematicElement>>(
+	schematics: &EngineSchematic<G>,
+	result: &mut Result,
+) {
 	for ((y, x), element) in schematics.grid.iter() {
 		if let SchematicElement::Number(n) = element.borrow().deref() {
 			result.grow_number(*n);
@@ -100,7 +120,7 @@ fn run_schematics(schematics: &mut EngineSchematic, result: &mut Result) {
 				if let SchematicElement::Symbol = adjacent.borrow().deref() {
 					result.is_part_number = true;
 				}
-				if let SchematicElement::Gear(_) = adjacent.borrow().deref() {
+				if let SchematicElement::Gear = adjacent.borrow().deref() {
 					result.is_part_number = true;
 					result.gear_point = Some(*point);
 				}
@@ -111,55 +131,68 @@ fn run_schematics(schematics: &mut EngineSchematic, result: &mut Result) {
 			match schematics.grid.get((*y, *x + 1)) {
 				Some(element) => match element.borrow().deref() {
 					SchematicElement::Symbol | SchematicElement::Empty => add_number = true,
-					SchematicElement::Gear(_) => add_number = true,
+					SchematicElement::Gear => add_number = true,
 					_ => (),
 				},
 				None => add_number = true,
 			}
 
 			if add_number {
-				if let Some(point) = result.gear_point {
-					match schematics.grid.get(point).unwrap().borrow_mut().deref_mut() {
-						SchematicElement::Gear(numbers) => numbers.push(result.number.clone()),
-						_ => unreachable!(),
-					}
-				}
 				result.add_part_if_part_number();
 			}
 		}
 	}
 }
 
-fn parse(input: &PuzzleInput) -> EngineSchematic {
+fn parse(input: &PuzzleInput) -> EngineSchematic<DenseGrid<isize, isize, SchematicElement>> {
 	input.trim().into()
 }
 
-fn part_one(input: &PuzzleInput, _args: &RawPuzzleArgs) -> Solution {
+fn part_one<G: GridAccess<isize, isize, SchematicElement>>(
+	schematics: &EngineSchematic<G>,
+	_args: &RawPuzzleArgs,
+) -> Solution {
 	let mut result = Result::new();
-	run_schematics(&mut parse(input), &mut result);
+	run_schematics(schematics, &mut result);
 
 	Answer(result.total())
 }
 
-fn part_two(input: &PuzzleInput, _args: &RawPuzzleArgs) -> Solution {
+fn part_two<G: GridAccess<isize, isize, SchematicElement>>(
+	schematics: &EngineSchematic<G>,
+	_args: &RawPuzzleArgs,
+) -> Solution {
 	let mut result = Result::new();
-	let mut schematics = parse(input);
-	run_schematics(&mut schematics, &mut result);
-
-	let total = schematics
-		.grid
-		.iter()
-		.filter(|(_, node)| match node.borrow().deref() {
-			SchematicElement::Gear(numbers) => return if numbers.iter().count() >= 2 { true } else { false },
-			_ => false,
-		})
-		.map(|(_, node)| match node.borrow().deref() {
-			SchematicElement::Gear(gears) => gears.iter().product::<u32>(),
-			_ => unreachable!(),
-		})
-		.sum::<u32>();
-
-	Answer(total as u64)
+	run_schematics(schematics, &mut result);
+
+	Answer(result.gear_ratios())
 }
 
-solve!(part_one, part_two);
+solve!(parse => part_one, part_two);
+
+#[cfg(test)]
+mod tests {
+	use advent_of_code::utils::benchmark::compare_implementations;
+	use advent_of_code::utils::grids::Grid;
+
+	use super::*;
+
+	fn solve<G: GridAccess<isize, isize, SchematicElement>>(input: &str) -> (Solution, Solution) {
+		let schematics: EngineSchematic<G> = input.trim().into();
+		let args = RawPuzzleArgs::new();
+		(part_one(&schematics, &args), part_two(&schematics, &args))
+	}
+
+	#[test]
+	#[ignore]
+	pub fn test_compare_grids() {
+		compare_implementations(
+			2023,
+			3,
+			&[
+				("Grid", &solve::<Grid<isize, isize, SchematicElement>>),
+				("DenseGrid", &solve::<DenseGrid<isize, isize, SchematicElement>>),
+			],
+		);
+	}
+}
@@ -34,7 +34,7 @@ impl ScratchCard {
 	}
 
 	fn matching_numbers_count(&self) -> u32 {
-		self.matching_numbers().iter().count() as u32
+		self.matching_numbers().len() as u32
 	}
 
 	fn matching_numbers_worth(&self) -> u32 {
@@ -62,7 +62,7 @@ fn parse(input: &PuzzleInput) -> Vec<ScratchCard> {
 
 			let numbers_of_card: &str = line.split(':').collect::<Vec<&str>>().get(1).unwrap();
 			let numbers_split: Vec<&str> = numbers_of_card.split('|').collect::<Vec<&str>>();
-			if let Some(winning_numbers) = numbers_split.get(0) {
+			if let Some(winning_numbers) = numbers_split.first() {
 				scratch_card.winning_numbers = NUMBERS
 					.find_iter(winning_numbers)
 					.map(|n| n.as_str().parse::<u32>().unwrap())
@@ -79,8 +79,7 @@ fn parse(input: &PuzzleInput) -> Vec<ScratchCard> {
 		.collect()
 }
 
-fn part_one(input: &PuzzleInput, _args: &RawPuzzleArgs) -> Solution {
-	let scratched_cards = parse(input);
+fn part_one(scratched_cards: &[ScratchCard], _args: &RawPuzzleArgs) -> Solution {
 	let result = scratched_cards
 		.iter()
 		.map(|sc| sc.matching_numbers_worth())
@@ -89,11 +88,10 @@ fn part_one(input: &PuzzleInput, _args: &RawPuzzleArgs) -> Solution {
 	Answer(result as u64)
 }
 
-fn part_two(input: &PuzzleInput, _args: &RawPuzzleArgs) -> Solution {
-	let scratched_cards = parse(input);
+fn part_two(scratched_cards: &[ScratchCard], _args: &RawPuzzleArgs) -> Solution {
 	let mut total = 0;
 	let mut copies: VecDeque<ScratchCard> = VecDeque::new();
-	copies.extend(scratched_cards.clone());
+	copies.extend(scratched_cards.iter().cloned());
 
 	while let Some(sc) = copies.pop_front() {
 		total += 1;
@@ -109,4 +107,4 @@ fn part_two(input: &PuzzleInput, _args: &RawPuzzleArgs) -> Solution {
 	Answer(total)
 }
 
-solve!(part_one, part_two);
+solve!(parse => part_one, part_two);
@@ -43,7 +43,7 @@ impl Almanac {
 	}
 
 	fn get_location_from_seed(&self, seed: &u64) -> (u64, u64) {
-		let soil = get_destination(&self.seed_to_soil, seed.into()).unwrap_or(*seed);
+		let soil = get_destination(&self.seed_to_soil, seed).unwrap_or(*seed);
 		let fertilizer = get_destination(&self.soil_to_fertilizer, &soil).unwrap_or(soil);
 		let water = get_destination(&self.fertilizer_to_water, &fertilizer).unwrap_or(fertilizer);
 		let light = get_destination(&self.water_to_light, &water).unwrap_or(water);
@@ -73,10 +73,10 @@ impl Almanac {
 	}
 }
 
-fn get_destination(ranges: &Vec<Range>, number: &u64) -> Option<u64> {
+fn get_destination(ranges: &[Range], number: &u64) -> Option<u64> {
 	for range in ranges.iter() {
-		if number >= &range.source && number <= &(&range.source + &range.length) {
-			return Some(&range.destination + &(number - &range.source));
+		if number >= &range.source && number <= &(range.source + range.length) {
+			return Some(range.destination + (number - range.source));
 		}
 	}
 	None
@@ -120,20 +120,18 @@ fn parse(input: &PuzzleInput) -> Almanac {
 	almanac
 }
 
-fn part_one(input: &PuzzleInput, _args: &RawPuzzleArgs) -> Solution {
-	let almanac = parse(input);
+fn part_one(almanac: &Almanac, _args: &RawPuzzleArgs) -> Solution {
 	let locations = almanac.seed_locations();
 	let min_location: &u64 = locations.iter().map(|(_, location)| location).min().unwrap();
 
 	Answer(*min_location)
 }
 
-fn part_two(input: &PuzzleInput, _args: &RawPuzzleArgs) -> Solution {
-	let almanac = parse(input);
+fn part_two(almanac: &Almanac, _args: &RawPuzzleArgs) -> Solution {
 	let locations = almanac.seed_range_locations();
 	let min_location: &u64 = locations.iter().map(|(_, location)| location).min().unwrap();
 
 	Answer(*min_location)
 }
 
-solve!(part_one, part_two);
+solve!(parse => part_one, part_two);
@@ -25,14 +25,40 @@ impl RaceRecord {
 	}
 
 	fn count_record_breakers(&self) -> usize {
-		self.record_breakers().iter().count()
+		self.record_breakers().len()
+	}
+
+	// The distance is a downward parabola symmetric around half the race time, so only the first
+	// record breaking hold time needs to be found. Floats are only used to jump close to it.
+	fn count_record_breakers_quadratic(&self) -> usize {
+		let (time, distance) = (self.time as f64, self.distance as f64);
+		let discriminant = time * time - 4.0 * distance;
+		if discriminant < 0.0 {
+			return 0;
+		}
+
+		let half = self.time / 2;
+		let breaks_record = |hold: u64| hold * (self.time - hold) > self.distance;
+		let mut first = (((time - discriminant.sqrt()) / 2.0) as u64).min(half);
+		while first > 0 && breaks_record(first - 1) {
+			first -= 1;
+		}
+		while first <= half && !breaks_record(first) {
+			first += 1;
+		}
+
+		if first > half {
+			0
+		} else {
+			(self.time - 2 * first + 1) as usize
+		}
 	}
 }
 
 fn parse(input: &PuzzleInput) -> Vec<RaceRecord> {
 	let mut lines = input.trim().lines();
-	let time_line = lines.nth(0).unwrap();
-	let distance_line = lines.nth(0).unwrap();
+	let time_line = lines.next().unwrap();
+	let distance_line = lines.next().unwrap();
 
 	let times: Vec<u64> = NUMBERS
 		.find_iter(time_line)
@@ -54,25 +80,39 @@ fn parse(input: &PuzzleInput) -> Vec<RaceRecord> {
 		.collect::<Vec<RaceRecord>>()
 }
 
-fn part_one(input: &PuzzleInput, _args: &RawPuzzleArgs) -> Solution {
-	let race_records = parse(input);
+fn single_race(race_records: &[RaceRecord]) -> RaceRecord {
+	let time: String = race_records.iter().map(|n| n.time.to_string()).collect();
+	let distance: String = race_records.iter().map(|n| n.distance.to_string()).collect();
+	RaceRecord {
+		time: time.parse::<u64>().unwrap(),
+		distance: distance.parse::<u64>().unwrap(),
+	}
+}
+
+fn part_one_iterative(race_records: &[RaceRecord], _args: &RawPuzzleArgs) -> Solution {
 	Answer(
 		race_records
 			.iter()
 			.map(|rr| rr.count_record_breakers() as u64)
-			.product::<u64>() as u64,
+			.product::<u64>(),
 	)
 }
 
-fn part_two(input: &PuzzleInput, _args: &RawPuzzleArgs) -> Solution {
-	let race_records = parse(input);
-	let time: String = race_records.iter().map(|n| n.time.to_string()).collect();
-	let distance: String = race_records.iter().map(|n| n.distance.to_string()).collect();
-	let rr = RaceRecord {
-		time: time.parse::<u64>().unwrap(),
-		distance: distance.parse::<u64>().unwrap(),
-	};
-	Answer(rr.count_record_breakers() as u64)
+fn part_one_quadratic(race_records: &[RaceRecord], _args: &RawPuzzleArgs) -> Solution {
+	Answer(
+		race_records
+			.iter()
+			.map(|rr| rr.count_record_breakers_quadratic() as u64)
+			.product::<u64>(),
+	)
+}
+
+fn part_two_iterative(race_records: &[RaceRecord], _args: &RawPuzzleArgs) -> Solution {
+	Answer(single_race(race_records).count_record_breakers() as u64)
+}
+
+fn part_two_quadratic(race_records: &[RaceRecord], _args: &RawPuzzleArgs) -> Solution {
+	Answer(single_race(race_records).count_record_breakers_quadratic() as u64)
 }
 
-solve!(part_one, part_two);
+solve!(parse => part_one[iterative, quadratic], part_two[iterative, quadratic]);
@@ -53,63 +53,62 @@ fn char_to_card_rank(c: char) -> CardRank {
 	}
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 struct Hand {
 	hand: String,
-	hand_rank: HandRank,
 	cards: BTreeMap<char, u32>,
 	bid: u64,
 }
 
 impl Hand {
-	fn calculate_rank(&mut self) {
+	fn rank(&self) -> HandRank {
 		match self.cards.values().max().unwrap() {
-			5 => self.hand_rank = HandRank::FiveOfAKind,
-			4 => self.hand_rank = HandRank::FourOfAKind,
+			5 => HandRank::FiveOfAKind,
+			4 => HandRank::FourOfAKind,
 			3 => {
-				if self.cards.iter().count() == 2 {
-					self.hand_rank = HandRank::FullHouse;
+				if self.cards.len() == 2 {
+					HandRank::FullHouse
 				} else {
-					self.hand_rank = HandRank::ThreeOfAKind;
+					HandRank::ThreeOfAKind
 				}
 			}
 			2 => {
-				if self.cards.iter().count() == 3 {
-					self.hand_rank = HandRank::TwoPair;
+				if self.cards.len() == 3 {
+					HandRank::TwoPair
 				} else {
-					self.hand_rank = HandRank::OnePair;
+					HandRank::OnePair
 				}
 			}
-			_ => self.hand_rank = HandRank::HighCard,
+			_ => HandRank::HighCard,
 		}
 	}
 
-	fn calculate_joker_rank(&mut self) {
+	fn joker_rank(&self) -> HandRank {
 		match self.cards.get(&'J') {
-			Some(4) | Some(5) => self.hand_rank = HandRank::FiveOfAKind,
-			Some(3) => match self.cards.iter().count() {
-				3 => self.hand_rank = HandRank::FourOfAKind,
-				2 => self.hand_rank = HandRank::FiveOfAKind,
+			Some(4) | Some(5) => HandRank::FiveOfAKind,
+			Some(3) => match self.cards.len() {
+				3 => HandRank::FourOfAKind,
+				2 => HandRank::FiveOfAKind,
 				_ => unreachable!(),
 			},
-			Some(2) => match self.cards.iter().count() {
-				4 => self.hand_rank = HandRank::ThreeOfAKind,
-				3 => self.hand_rank = HandRank::FourOfAKind,
-				2 => self.hand_rank = HandRank::FiveOfAKind,
+			Some(2) => match self.cards.len() {
+				4 => HandRank::ThreeOfAKind,
+				3 => HandRank::FourOfAKind,
+				2 => HandRank::FiveOfAKind,
 				_ => unreachable!(),
 			},
-			Some(1) => match self.cards.iter().count() {
-				5 => self.hand_rank = HandRank::OnePair,
-				4 => self.hand_rank = HandRank::ThreeOfAKind,
+			Some(1) => match self.cards.len() {
+				5 => HandRank::OnePair,
+				4 => HandRank::ThreeOfAKind,
 				3 => match self.cards.values().max().unwrap() {
-					3 => self.hand_rank = HandRank::FourOfAKind,
-					2 => self.hand_rank = HandRank::FullHouse,
+					3 => HandRank::FourOfAKind,
+					2 => HandRank::FullHouse,
 					_ => unreachable!(),
 				},
-				2 => self.hand_rank = HandRank::FiveOfAKind,
+				2 => HandRank::FiveOfAKind,
 				_ => unreachable!(),
 			},
-			None => self.calculate_rank(),
+			None => self.rank(),
 			_ => unreachable!(),
 		}
 	}
@@ -121,18 +120,15 @@ struct Game {
 }
 
 impl Game {
-	fn calculate_hands(&mut self) {
-		self.hands.iter_mut().map(|h| h.calculate_rank()).collect()
-	}
-
-	fn calculate_joker_hands(&mut self) {
-		self.hands.iter_mut().map(|h| h.calculate_joker_rank()).collect()
-	}
-
-	fn sort_hands(&mut self, joker: bool) {
-		self.hands.sort_by(|a, b| {
-			if a.hand_rank != b.hand_rank {
-				return a.hand_rank.cmp(&b.hand_rank);
+	fn ranked_hands(&self, joker: bool) -> Vec<(HandRank, &Hand)> {
+		let mut hands: Vec<(HandRank, &Hand)> = self
+			.hands
+			.iter()
+			.map(|hand| (if joker { hand.joker_rank() } else { hand.rank() }, hand))
+			.collect();
+		hands.sort_by(|(a_rank, a), (b_rank, b)| {
+			if a_rank != b_rank {
+				return a_rank.cmp(b_rank);
 			}
 
 			let mut a_cards = a.hand.chars();
@@ -157,7 +153,7 @@ impl Game {
 							}
 						}
 						if let (CardRank::Num(ax), CardRank::Num(bx)) = (&a, &b) {
-							return bx.cmp(&ax);
+							return bx.cmp(ax);
 						}
 						return ordering;
 					}
@@ -165,7 +161,16 @@ impl Game {
 			}
 
 			Ordering::Equal
-		})
+		});
+		hands
+	}
+
+	fn winnings(&self, joker: bool) -> u64 {
+		self.ranked_hands(joker)
+			.iter()
+			.rev()
+			.enumerate()
+			.fold(0, |acc, (index, (_, hand))| acc + (index as u64 + 1) * hand.bid)
 	}
 }
 
@@ -185,7 +190,6 @@ fn parse(input: &PuzzleInput) -> Game {
 
 		let hand = Hand {
 			hand: String::from(&captures["hand"]),
-			hand_rank: HandRank::Undefined,
 			cards,
 			bid: captures["bid"].parse::<u64>().unwrap(),
 		};
@@ -195,34 +199,12 @@ fn parse(input: &PuzzleInput) -> Game {
 	Game { hands }
 }
 
-fn part_one(input: &PuzzleInput, _args: &RawPuzzleArgs) -> Solution {
-	let mut game = parse(input);
-	game.calculate_hands();
-	game.sort_hands(false);
-	game.hands.reverse();
-
-	let total: u64 = game
-		.hands
-		.iter()
-		.enumerate()
-		.fold(0, |acc, (index, value)| acc + (index as u64 + 1) * value.bid);
-
-	Answer(total)
+fn part_one(game: &Game, _args: &RawPuzzleArgs) -> Solution {
+	Answer(game.winnings(false))
 }
 
-fn part_two(input: &PuzzleInput, _args: &RawPuzzleArgs) -> Solution {
-	let mut game = parse(input);
-	game.calculate_joker_hands();
-	game.sort_hands(true);
-	game.hands.reverse();
-
-	let total: u64 = game
-		.hands
-		.iter()
-		.enumerate()
-		.fold(0, |acc, (index, value)| acc + (index as u64 + 1) * value.bid);
-
-	Answer(total)
+fn part_two(game: &Game, _args: &RawPuzzleArgs) -> Solution {
+	Answer(game.winnings(true))
 }
 
-solve!(part_one, part_two);
+solve!(parse => part_one, part_two);
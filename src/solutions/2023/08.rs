@@ -56,27 +56,26 @@ fn parse(input: &PuzzleInput) -> Game {
 	}
 }
 
-fn part_one(input: &PuzzleInput, _args: &RawPuzzleArgs) -> Solution {
-	let mut game = parse(input);
+fn part_one(game: &Game, _args: &RawPuzzleArgs) -> Solution {
+	let mut instructions = game.instructions.clone();
 	let mut index = 0;
 	let mut current_node: &str = "AAA";
 
 	while current_node != "ZZZ" {
 		index += 1;
 		let (left, right) = game.nodes.get(current_node).unwrap();
-		let direction = game.instructions.pop_front().unwrap();
+		let direction = instructions.pop_front().unwrap();
 		current_node = match direction {
 			Direction::Left => left,
 			Direction::Right => right,
 		};
-		game.instructions.push_back(direction);
+		instructions.push_back(direction);
 	}
 
 	Answer(index)
 }
 
-fn part_two(input: &PuzzleInput, _args: &RawPuzzleArgs) -> Solution {
-	let game = parse(input);
+fn part_two(game: &Game, _args: &RawPuzzleArgs) -> Solution {
 	let mut result: HashMap<String, u32> = HashMap::new();
 
 	for node in game.current_nodes.iter() {
@@ -107,4 +106,4 @@ fn part_two(input: &PuzzleInput, _args: &RawPuzzleArgs) -> Solution {
 	)
 }
 
-solve!(part_one, part_two);
+solve!(parse => part_one, part_two);
@@ -8,17 +8,17 @@ lazy_static! {
 #[derive(Debug)]
 struct History {
 	values: Vec<i32>,
-	calculations: Vec<Vec<i32>>,
 }
 
 impl History {
-	fn calculate(&mut self, reverse: bool) -> &mut Self {
+	fn calculate(&self, reverse: bool) -> Vec<Vec<i32>> {
+		let mut values = self.values.clone();
 		if reverse {
-			self.values.reverse();
+			values.reverse();
 		}
-		let mut values = self.values.clone();
+		let mut calculations = Vec::new();
 		while !self.all_zeros(&values) {
-			self.calculations.push(values.clone());
+			calculations.push(values.clone());
 			let mut new_values = Vec::new();
 
 			for window in values.windows(2) {
@@ -28,14 +28,14 @@ impl History {
 			}
 			values = new_values
 		}
-		self
+		calculations
 	}
 
-	fn prediction(&self) -> i32 {
-		self.calculations.iter().map(|list| list.iter().last().unwrap()).sum()
+	fn prediction(&self, reverse: bool) -> i32 {
+		self.calculate(reverse).iter().map(|list| list.iter().last().unwrap()).sum()
 	}
 
-	fn all_zeros(&self, values: &Vec<i32>) -> bool {
+	fn all_zeros(&self, values: &[i32]) -> bool {
 		for v in values.iter() {
 			if *v != 0 {
 				return false;
@@ -55,29 +55,16 @@ fn parse(input: &PuzzleInput) -> Vec<History> {
 				.find_iter(line)
 				.map(|n| n.as_str().parse::<i32>().unwrap())
 				.collect(),
-			calculations: Vec::new(),
 		})
 		.collect()
 }
 
-fn part_one(input: &PuzzleInput, _args: &RawPuzzleArgs) -> Solution {
-	let mut oasis_report = parse(input);
-	Answer(
-		oasis_report
-			.iter_mut()
-			.map(|h| h.calculate(false).prediction())
-			.sum::<i32>() as u64,
-	)
+fn part_one(oasis_report: &[History], _args: &RawPuzzleArgs) -> Solution {
+	Answer(oasis_report.iter().map(|h| h.prediction(false)).sum::<i32>() as u64)
 }
 
-fn part_two(input: &PuzzleInput, _args: &RawPuzzleArgs) -> Solution {
-	let mut oasis_report = parse(input);
-	Answer(
-		oasis_report
-			.iter_mut()
-			.map(|h| h.calculate(true).prediction())
-			.sum::<i32>() as u64,
-	)
+fn part_two(oasis_report: &[History], _args: &RawPuzzleArgs) -> Solution {
+	Answer(oasis_report.iter().map(|h| h.prediction(true)).sum::<i32>() as u64)
 }
 
-solve!(part_one, part_two);
+solve!(parse => part_one, part_two);
@@ -3,11 +3,18 @@ use std::cmp::PartialEq;
 use std::fmt;
 use std::fmt::Formatter;
 use std::ops::Deref;
+use std::sync::Arc;
 
 use num::One;
 
 use advent_of_code::utils::challenges::prelude::*;
-use advent_of_code::utils::grids::{Grid, GridPoint};
+use advent_of_code::utils::grids::{DenseGrid, GridAccess, GridPoint};
+use advent_of_code::utils::visualize::{self, Frame, Snapshot};
+
+puzzle_args!(
+	#[example = 1]
+	frame_every: u64 = 100,
+);
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 enum Direction {
@@ -48,13 +55,16 @@ impl fmt::Display for Pipe {
 }
 
 #[derive(Debug, Default)]
-struct Landscape {
-	grid: Grid<isize, isize, Pipe>,
+struct Landscape<G> {
+	grid: G,
 }
 
-impl From<&str> for Landscape {
+impl<G: GridAccess<isize, isize, Pipe>> From<&str> for Landscape<G> {
 	fn from(schematic: &str) -> Self {
-		let mut landscape = Self { ..Self::default() };
+		let width = schematic.lines().map(str::len).max().unwrap_or(0);
+		let mut landscape = Self {
+			grid: G::with_size(schematic.lines().count(), width),
+		};
 		for (y, line) in schematic.lines().enumerate() {
 			for (x, char) in line.chars().enumerate() {
 				let point = match char {
@@ -114,12 +124,15 @@ fn direction_to_go(direction: &Direction, pipe: &Pipe, grid_point: &GridPoint<is
 	}
 }
 
-fn parse(input: &PuzzleInput) -> Landscape {
+fn parse(input: &PuzzleInput) -> Landscape<DenseGrid<isize, isize, Pipe>> {
 	input.trim().into()
 }
 
-fn part_one(input: &PuzzleInput, _args: &RawPuzzleArgs) -> Solution {
-	let landscape = parse(input);
+fn part_one<G>(landscape: &Landscape<G>, args: &RawPuzzleArgs) -> Solution
+where
+	G: GridAccess<isize, isize, Pipe>,
+	for<'a> Snapshot: From<&'a G>,
+{
 	let start_point = &landscape
 		.grid
 		.iter()
@@ -138,19 +151,72 @@ fn part_one(input: &PuzzleInput, _args: &RawPuzzleArgs) -> Solution {
 		}
 	}
 
+	let snapshot = visualize::is_enabled().then(|| Arc::new(Snapshot::from(&landscape.grid)));
+	let frame_every = PuzzleArgs::from(args).frame_every.max(1);
+	let mut path = vec![*start_point.0];
+
 	let mut count: u64 = 0;
 	let mut next_pipe = landscape.grid.get(next_direction.1).unwrap().borrow();
 	while next_pipe.deref() != &Pipe::StartPosition {
 		count += 1;
+		if let Some(snapshot) = &snapshot {
+			path.push(next_direction.1);
+			if count.is_multiple_of(frame_every) {
+				visualize::push(
+					Frame::new(snapshot)
+						.path(&path, Color::Green)
+						.highlight(next_direction.1, Color::BrightYellow)
+						.caption(format!("Step {}", count)),
+				);
+			}
+		}
 		next_direction = direction_to_go(&next_direction.0.unwrap(), &next_pipe, &next_direction.1);
 		next_pipe = landscape.grid.get(next_direction.1).unwrap().borrow();
 	}
 
+	if let Some(snapshot) = &snapshot {
+		visualize::push(
+			Frame::new(snapshot)
+				.path(&path, Color::Green)
+				.caption(format!("Farthest point after {} steps", count / 2 + 1)),
+		);
+	}
+
 	Answer(count / 2 + 1)
 }
 
-fn part_two(_input: &PuzzleInput, _args: &RawPuzzleArgs) -> Solution {
+fn part_two<G>(_landscape: &Landscape<G>, _args: &RawPuzzleArgs) -> Solution {
 	Unsolved
 }
 
-solve!(part_one, part_two);
+solve!(parse => part_one, part_two);
+
+#[cfg(test)]
+mod tests {
+	use advent_of_code::utils::benchmark::compare_implementations;
+	use advent_of_code::utils::grids::Grid;
+
+	use super::*;
+
+	fn solve<G>(input: &str) -> Solution
+	where
+		G: GridAccess<isize, isize, Pipe>,
+		for<'a> Snapshot: From<&'a G>,
+	{
+		let landscape: Landscape<G> = input.trim().into();
+		part_one(&landscape, &RawPuzzleArgs::new())
+	}
+
+	#[test]
+	#[ignore]
+	pub fn test_compare_grids() {
+		compare_implementations(
+			2023,
+			10,
+			&[
+				("Grid", &solve::<Grid<isize, isize, Pipe>>),
+				("DenseGrid", &solve::<DenseGrid<isize, isize, Pipe>>),
+			],
+		);
+	}
+}
@@ -1,7 +1,8 @@
 use advent_of_code::utils::challenges::prelude::*;
 
-fn parse(input: &PuzzleInput) {
-	todo!("parse input file correctly and set return type correctly")
+// TODO: parse input file correctly and set return type correctly
+fn parse(input: &PuzzleInput) -> PuzzleInput {
+	input.to_owned()
 }
 
 fn part_one(_input: &PuzzleInput, _args: &RawPuzzleArgs) -> Solution {
@@ -12,4 +13,4 @@ fn part_two(_input: &PuzzleInput, _args: &RawPuzzleArgs) -> Solution {
 	Unsolved
 }
 
-solve!(part_one, part_two);
+solve!(parse => part_one, part_two);
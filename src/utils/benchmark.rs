@@ -0,0 +1,54 @@
+use std::fmt::Debug;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use super::challenges::{inputs, Config, Day, PuzzleInput, Year};
+
+/// The stored input of a day, `None` when it is missing as nothing is downloaded
+pub fn stored_input(year: Year, day: Day) -> Option<PuzzleInput> {
+	let config = Config {
+		offline: true,
+		..Config::from_env().ok()?
+	};
+	inputs::input(year, day, &config).ok()
+}
+
+/// Median runtime of a number of runs, used by ignored tests that compare implementations
+pub fn median_runtime<T>(runs: usize, mut f: impl FnMut() -> T) -> Duration {
+	let mut runtimes: Vec<Duration> = (0..runs.max(1))
+		.map(|_| {
+			let start = Instant::now();
+			black_box(f());
+			start.elapsed()
+		})
+		.collect();
+	runtimes.sort();
+	runtimes[runtimes.len() / 2]
+}
+
+/// A named way of solving a puzzle input, e.g. the same solution on top of another data structure
+pub type Implementation<'a, T> = (&'a str, &'a dyn Fn(&str) -> T);
+
+/// Runs every implementation on the stored input of a day, they must all give the answers of the first one
+///
+/// Used by ignored tests, which print the median runtimes with
+/// `cargo test --release --bin runner -- --ignored --nocapture`.
+pub fn compare_implementations<T: Debug + PartialEq>(year: Year, day: Day, implementations: &[Implementation<T>]) {
+	let input = stored_input(year, day).unwrap_or_else(|| panic!("The input of {} day {} is not stored", year, day));
+
+	let (first, solve_first) = implementations.first().expect("Nothing to compare");
+	let answers = solve_first(&input);
+	for (name, solve) in &implementations[1..] {
+		assert_eq!(solve(&input), answers, "{} disagrees with {}", name, first);
+	}
+
+	for (name, solve) in implementations {
+		println!(
+			"{} day {}: {} {:?}",
+			year,
+			day,
+			name,
+			median_runtime(10, || solve(&input))
+		);
+	}
+}
@@ -0,0 +1,295 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::Deserialize;
+use thiserror::Error;
+
+/// A value passed to a solution with `--arg name=value`, tuples such as `(101, 103)` are stored as lists
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum PuzzleArg {
+	Boolean(bool),
+	Number(u64),
+	Integer(i64),
+	Float(f64),
+	String(String),
+	List(Vec<PuzzleArg>),
+}
+
+/// The input the parts are solving, puzzle arguments can have different defaults for the examples
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum InputKind {
+	#[default]
+	Real,
+	Example,
+}
+
+/// The arguments passed with `--arg`, together with the kind of input the parts are solving
+#[derive(Debug, Clone, Default)]
+pub struct RawPuzzleArgs {
+	pub input: InputKind,
+	values: BTreeMap<String, PuzzleArg>,
+}
+
+impl RawPuzzleArgs {
+	pub fn get(&self, name: &str) -> Option<&PuzzleArg> {
+		self.values.get(name)
+	}
+
+	pub fn insert(&mut self, name: &str, value: PuzzleArg) {
+		self.values.insert(name.to_string(), value);
+	}
+
+	pub fn iter(&self) -> impl Iterator<Item = (&String, &PuzzleArg)> {
+		self.values.iter()
+	}
+
+	pub fn new() -> RawPuzzleArgs {
+		RawPuzzleArgs::default()
+	}
+}
+
+impl Extend<(String, PuzzleArg)> for RawPuzzleArgs {
+	fn extend<T: IntoIterator<Item = (String, PuzzleArg)>>(&mut self, iter: T) {
+		self.values.extend(iter);
+	}
+}
+
+#[derive(Debug, Error, PartialEq)]
+#[error("Puzzle argument `{name}` must be {expected}, got `{value}`")]
+pub struct PuzzleArgError {
+	pub name: String,
+	pub expected: String,
+	pub value: PuzzleArg,
+}
+
+/// Accepts any arguments, solutions that declare `puzzle_args!` get a check of their own that shadows this one
+pub fn check_puzzle_args(_raw_args: &RawPuzzleArgs) -> Result<(), PuzzleArgError> {
+	Ok(())
+}
+
+/// Splits a list on the commas that are not nested in another list
+fn split_list(list: &str) -> Vec<&str> {
+	let mut items = Vec::new();
+	let (mut depth, mut start) = (0, 0);
+	for (index, char) in list.char_indices() {
+		match char {
+			'(' | '[' => depth += 1,
+			')' | ']' => depth -= 1,
+			',' if depth == 0 => {
+				items.push(&list[start..index]);
+				start = index + 1;
+			}
+			_ => {}
+		}
+	}
+	items.push(&list[start..]);
+	items
+		.into_iter()
+		.map(str::trim)
+		.filter(|item| !item.is_empty())
+		.collect()
+}
+
+impl PuzzleArg {
+	/// Parses a value from the command line, anything that isn't a boolean, number or list is a string
+	pub fn parse(value: &str) -> PuzzleArg {
+		let value = value.trim();
+		let list = value
+			.strip_prefix('(')
+			.and_then(|list| list.strip_suffix(')'))
+			.or_else(|| value.strip_prefix('[').and_then(|list| list.strip_suffix(']')));
+
+		if let Some(list) = list {
+			PuzzleArg::List(split_list(list).into_iter().map(PuzzleArg::parse).collect())
+		} else if let Ok(bool) = value.parse() {
+			PuzzleArg::Boolean(bool)
+		} else if let Ok(nr) = value.parse() {
+			PuzzleArg::Number(nr)
+		} else if let Ok(nr) = value.parse() {
+			PuzzleArg::Integer(nr)
+		} else if let Some(nr) = value
+			.parse()
+			.ok()
+			.filter(|_| value.contains(|c: char| c.is_ascii_digit()))
+		{
+			PuzzleArg::Float(nr)
+		} else {
+			PuzzleArg::String(value.trim_matches('"').to_string())
+		}
+	}
+}
+
+impl fmt::Display for PuzzleArg {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			PuzzleArg::Boolean(bool) => write!(f, "{}", bool),
+			PuzzleArg::Number(nr) => write!(f, "{}", nr),
+			PuzzleArg::Integer(nr) => write!(f, "{}", nr),
+			PuzzleArg::Float(nr) => write!(f, "{:?}", nr),
+			PuzzleArg::String(str) => write!(f, "{}", str),
+			PuzzleArg::List(items) => {
+				let items: Vec<String> = items.iter().map(PuzzleArg::to_string).collect();
+				write!(f, "({})", items.join(", "))
+			}
+		}
+	}
+}
+
+/// Types that can be read from a `PuzzleArg`, used by `puzzle_args!`
+pub trait FromPuzzleArg: Sized {
+	/// Describes the type in errors, e.g. `an integer (i64)`
+	fn expected() -> String;
+
+	fn from_arg(arg: &PuzzleArg) -> Option<Self>;
+
+	fn extract(name: &str, arg: &PuzzleArg) -> Result<Self, PuzzleArgError> {
+		Self::from_arg(arg).ok_or_else(|| PuzzleArgError {
+			name: name.to_string(),
+			expected: Self::expected(),
+			value: arg.clone(),
+		})
+	}
+}
+
+impl FromPuzzleArg for bool {
+	fn expected() -> String {
+		"a boolean".to_string()
+	}
+
+	fn from_arg(arg: &PuzzleArg) -> Option<Self> {
+		match arg {
+			PuzzleArg::Boolean(bool) => Some(*bool),
+			_ => None,
+		}
+	}
+}
+
+impl FromPuzzleArg for String {
+	fn expected() -> String {
+		"a string".to_string()
+	}
+
+	fn from_arg(arg: &PuzzleArg) -> Option<Self> {
+		match arg {
+			PuzzleArg::String(str) => Some(str.to_string()),
+			_ => None,
+		}
+	}
+}
+
+macro_rules! impl_from_puzzle_arg_for_integers {
+	($expected:literal: $($type:ty),+) => {
+		$(
+			impl FromPuzzleArg for $type {
+				fn expected() -> String {
+					format!("{} ({})", $expected, stringify!($type))
+				}
+
+				fn from_arg(arg: &PuzzleArg) -> Option<Self> {
+					match arg {
+						PuzzleArg::Number(nr) => (*nr).try_into().ok(),
+						PuzzleArg::Integer(nr) => (*nr).try_into().ok(),
+						_ => None,
+					}
+				}
+			}
+		)+
+	};
+}
+
+impl_from_puzzle_arg_for_integers!("an unsigned integer": u8, u16, u32, u64, usize);
+impl_from_puzzle_arg_for_integers!("an integer": i8, i16, i32, i64, isize);
+
+impl FromPuzzleArg for f64 {
+	fn expected() -> String {
+		"a number".to_string()
+	}
+
+	fn from_arg(arg: &PuzzleArg) -> Option<Self> {
+		match arg {
+			PuzzleArg::Number(nr) => Some(*nr as f64),
+			PuzzleArg::Integer(nr) => Some(*nr as f64),
+			PuzzleArg::Float(nr) => Some(*nr),
+			_ => None,
+		}
+	}
+}
+
+impl<T: FromPuzzleArg> FromPuzzleArg for Vec<T> {
+	fn expected() -> String {
+		format!("a list of {}", T::expected())
+	}
+
+	fn from_arg(arg: &PuzzleArg) -> Option<Self> {
+		match arg {
+			PuzzleArg::List(items) => items.iter().map(T::from_arg).collect(),
+			_ => None,
+		}
+	}
+}
+
+macro_rules! impl_from_puzzle_arg_for_tuples {
+	($(($($type:ident),+)),+) => {
+		$(
+			impl<$($type: FromPuzzleArg),+> FromPuzzleArg for ($($type,)+) {
+				fn expected() -> String {
+					let types: Vec<String> = vec![$($type::expected()),+];
+					format!("a tuple of ({})", types.join(", "))
+				}
+
+				#[allow(non_snake_case)]
+				fn from_arg(arg: &PuzzleArg) -> Option<Self> {
+					match arg {
+						PuzzleArg::List(items) => match items.as_slice() {
+							[$($type),+] => Some(($($type::from_arg($type)?,)+)),
+							_ => None,
+						},
+						_ => None,
+					}
+				}
+			}
+		)+
+	};
+}
+
+impl_from_puzzle_arg_for_tuples!((A, B), (A, B, C), (A, B, C, D));
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	pub fn test_parse() {
+		assert_eq!(PuzzleArg::parse("true"), PuzzleArg::Boolean(true));
+		assert_eq!(PuzzleArg::parse("12"), PuzzleArg::Number(12));
+		assert_eq!(PuzzleArg::parse("-3"), PuzzleArg::Integer(-3));
+		assert_eq!(PuzzleArg::parse("0.5"), PuzzleArg::Float(0.5));
+		assert_eq!(PuzzleArg::parse("\"abc\""), PuzzleArg::String("abc".to_string()));
+		assert_eq!(PuzzleArg::parse("inf"), PuzzleArg::String("inf".to_string()));
+		assert_eq!(
+			PuzzleArg::parse("(101, [1, -2])"),
+			PuzzleArg::List(vec![
+				PuzzleArg::Number(101),
+				PuzzleArg::List(vec![PuzzleArg::Number(1), PuzzleArg::Integer(-2)])
+			])
+		);
+		assert_eq!(PuzzleArg::parse("(101, 103)").to_string(), "(101, 103)");
+	}
+
+	#[test]
+	pub fn test_extract() {
+		let size = PuzzleArg::parse("(101, 103)");
+		assert_eq!(<(usize, i32)>::extract("size", &size), Ok((101, 103)));
+		assert_eq!(<Vec<u8>>::extract("size", &size), Ok(vec![101, 103]));
+		assert_eq!(f64::extract("ratio", &PuzzleArg::parse("-2")), Ok(-2.0));
+
+		let error = <(u64, u64, u64)>::extract("size", &size).unwrap_err();
+		assert_eq!(
+			error.to_string(),
+			"Puzzle argument `size` must be a tuple of (an unsigned integer (u64), an unsigned integer (u64), an \
+			 unsigned integer (u64)), got `(101, 103)`"
+		);
+		assert!(u8::extract("limit", &PuzzleArg::parse("-1")).is_err());
+	}
+}
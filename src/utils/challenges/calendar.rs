@@ -0,0 +1,190 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::PathBuf;
+
+use colored::{ColoredString, Colorize};
+use serde::{Deserialize, Serialize};
+
+use super::{
+	manifest_path, unlock, ChallengeError, ChallengeReport, Config, Day, PartReport, Solution, Year, STATE_DIRECTORY,
+};
+
+/// Days shown per row of the calendar
+const WEEK: Day = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PartStatus {
+	/// The answer matches the expected answer of the puzzle
+	Verified,
+	/// An answer without an expected answer to compare against
+	Answered,
+	/// The answer differs from the expected answer, or the variants of the part disagree
+	Wrong,
+	Unsolved,
+	/// The input is missing or the solution panicked
+	Failed,
+	/// The input is stored, but the day has not been run since
+	NotRun,
+}
+
+impl PartStatus {
+	pub fn from_part(part: &PartReport) -> PartStatus {
+		match (part.answer(), &part.expected) {
+			(Solution::Unsolved, _) => PartStatus::Unsolved,
+			_ if !part.variants_agree() => PartStatus::Wrong,
+			(_, None) => PartStatus::Answered,
+			(_, Some(_)) if part.is_mismatch() => PartStatus::Wrong,
+			_ => PartStatus::Verified,
+		}
+	}
+
+	/// Both parts of a day that couldn't run count as failed
+	pub fn from_result(result: &Result<ChallengeReport, ChallengeError>) -> Vec<PartStatus> {
+		match result {
+			Ok(report) => report.parts.iter().map(PartStatus::from_part).collect(),
+			Err(_) => vec![PartStatus::Failed; 2],
+		}
+	}
+
+	/// Whether the part would have earned a star
+	pub fn is_solved(&self) -> bool {
+		matches!(self, PartStatus::Verified | PartStatus::Answered)
+	}
+
+	/// Symbol of the status in the calendar and in the progress tables of the README
+	pub fn symbol(&self) -> &'static str {
+		match self {
+			PartStatus::Verified => "★",
+			PartStatus::Answered => "☆",
+			PartStatus::Wrong => "✗",
+			PartStatus::Unsolved => "·",
+			PartStatus::Failed => "!",
+			PartStatus::NotRun => "?",
+		}
+	}
+
+	fn colored_symbol(&self) -> ColoredString {
+		match self {
+			PartStatus::Verified => self.symbol().bright_yellow(),
+			PartStatus::Answered => self.symbol().green(),
+			PartStatus::NotRun => self.symbol().bright_black(),
+			_ => self.symbol().red(),
+		}
+	}
+}
+
+/// The registered days of a year with the status of their parts, days without a solution are left out
+pub type YearProgress = BTreeMap<Day, Vec<PartStatus>>;
+
+/// Statuses of the last runs with the real input of a profile, so the calendar doesn't have to solve everything again
+fn progress_path(config: &Config) -> PathBuf {
+	let name = match &config.profile {
+		Some(profile) => format!("progress-{}.json", profile),
+		None => "progress.json".to_string(),
+	};
+	manifest_path(STATE_DIRECTORY).join(name)
+}
+
+/// The stored statuses per year, empty when nothing has been run yet
+pub fn load_progress(config: &Config) -> BTreeMap<Year, YearProgress> {
+	fs::read_to_string(progress_path(config))
+		.ok()
+		.and_then(|json| serde_json::from_str(&json).ok())
+		.unwrap_or_default()
+}
+
+/// Stores the statuses of a run, days that were not part of it keep their earlier status
+pub fn save_progress(config: &Config, run: &BTreeMap<Year, YearProgress>) -> Result<(), ChallengeError> {
+	let mut progress = load_progress(config);
+	for (year, days) in run {
+		progress.entry(*year).or_default().extend(days.clone());
+	}
+
+	let path = progress_path(config);
+	let directory = manifest_path(STATE_DIRECTORY);
+	fs::create_dir_all(&directory).map_err(|source| ChallengeError::Io {
+		path: directory,
+		source,
+	})?;
+	let json = serde_json::to_string(&progress).expect("Statuses can always be serialized");
+	fs::write(&path, json).map_err(|source| ChallengeError::Io { path, source })
+}
+
+fn print_day(day: Day, parts: Option<&Vec<PartStatus>>) {
+	match parts {
+		Some(parts) => {
+			let symbols: String = parts.iter().map(|part| part.colored_symbol().to_string()).collect();
+			let padding = " ".repeat(2usize.saturating_sub(parts.len()));
+			print!("  {:02} {}{}", day, symbols, padding);
+		}
+		None => print!("  {}   ", format!("{:02}", day).bright_black()),
+	}
+}
+
+/// Renders the days of the year as a grid of weeks, followed by the number of stars and verified answers
+pub fn print_year(year: Year, progress: &YearProgress) {
+	println!("{}", year.to_string().bold());
+
+	let days = unlock::days_in_event(year);
+	for week_start in (1..=days).step_by(WEEK.into()) {
+		for day in week_start..(week_start + WEEK).min(days + 1) {
+			print_day(day, progress.get(&day));
+		}
+		println!();
+	}
+
+	let parts = progress.values().flatten();
+	let stars = parts.clone().filter(|part| part.is_solved()).count();
+	let verified = parts.filter(|part| **part == PartStatus::Verified).count();
+	println!(
+		"{}",
+		format!("  {}/{} stars, {} verified", stars, usize::from(days) * 2, verified).bright_black()
+	);
+}
+
+/// Lists the days where the stars earned on the website differ from the parts solved in this repository
+pub fn print_star_comparison(progress: &YearProgress, stars: &BTreeMap<Day, u8>) {
+	let days: BTreeSet<&Day> = progress.keys().chain(stars.keys()).collect();
+	let differences: Vec<(Day, u8, usize)> = days
+		.into_iter()
+		.map(|day| {
+			let website = stars.get(day).copied().unwrap_or(0);
+			let local = progress
+				.get(day)
+				.map_or(0, |parts| parts.iter().filter(|part| part.is_solved()).count());
+			(*day, website, local)
+		})
+		.filter(|(_, website, local)| usize::from(*website) != *local)
+		.collect();
+
+	if differences.is_empty() {
+		println!("  {}", "Matches the stars on the website".green());
+	}
+	for (day, website, local) in differences {
+		println!(
+			"  {}",
+			format!(
+				"Day {:02}: {} star(s) on the website, {} part(s) solved here",
+				day, website, local
+			)
+			.yellow()
+		);
+	}
+}
+
+pub fn print_legend() {
+	let legend = [
+		PartStatus::Verified,
+		PartStatus::Answered,
+		PartStatus::Wrong,
+		PartStatus::Unsolved,
+		PartStatus::Failed,
+		PartStatus::NotRun,
+	]
+	.iter()
+	.zip(["verified", "answered", "wrong", "unsolved", "failed", "not run"])
+	.map(|(status, label)| format!("{} {}", status.colored_symbol(), label.bright_black()))
+	.collect::<Vec<_>>()
+	.join("  ");
+	println!("{}", legend);
+}
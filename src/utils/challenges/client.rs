@@ -0,0 +1,190 @@
+use std::collections::BTreeMap;
+use std::env;
+
+use reqwest::header::{HeaderMap, HeaderValue, COOKIE, USER_AGENT};
+use reqwest::redirect::Policy;
+use reqwest::StatusCode;
+
+use super::config::load_env;
+use super::html::{calendar_stars, description_markdown};
+use super::{ChallengeError, Config, Day, PuzzleInput, Year};
+
+pub const AOC_URL: &str = "https://adventofcode.com";
+const AOC_USER_AGENT: &str = "github.com/wesleylether/advent-of-code";
+
+/// Authenticated HTTP client for the Advent of Code website
+#[derive(Debug)]
+pub struct AocClient {
+	client: reqwest::blocking::Client,
+	base_url: String,
+	session_var: String,
+}
+
+impl AocClient {
+	/// Creates a client with the session of the configured profile
+	pub fn from_config(config: &Config) -> Result<AocClient, ChallengeError> {
+		load_env();
+
+		let session_var = config.session_var();
+		let aoc_session = env::var(&session_var)
+			.ok()
+			.filter(|session| !session.is_empty())
+			.ok_or_else(|| ChallengeError::MissingSession(session_var.clone()))?;
+
+		let mut client = AocClient::new(&aoc_session)?.with_base_url(&config.aoc_url);
+		client.session_var = session_var;
+		Ok(client)
+	}
+
+	pub fn new(aoc_session: &str) -> Result<AocClient, ChallengeError> {
+		let mut headers = HeaderMap::new();
+		headers.insert(
+			COOKIE,
+			HeaderValue::from_str(&format!("session={}", aoc_session)).map_err(|_| ChallengeError::InvalidSession)?,
+		);
+		headers.insert(USER_AGENT, HeaderValue::from_static(AOC_USER_AGENT));
+
+		// Without a valid session the site redirects to the login page, which must not be followed
+		let client = reqwest::blocking::Client::builder()
+			.default_headers(headers)
+			.redirect(Policy::none())
+			.build()
+			.expect("Couldn't create the http client");
+
+		Ok(AocClient {
+			client,
+			base_url: AOC_URL.to_string(),
+			session_var: "AOC_SESSION".to_string(),
+		})
+	}
+
+	/// Sends requests to another server than the website, e.g. a local server with fixtures
+	pub fn with_base_url(mut self, base_url: &str) -> AocClient {
+		self.base_url = base_url.trim_end_matches('/').to_string();
+		self
+	}
+
+	pub fn url(&self, path: &str) -> String {
+		format!("{}{}", self.base_url, path)
+	}
+
+	fn get(&self, path: &str) -> Result<String, ChallengeError> {
+		let url = self.url(path);
+		let response = self.client.get(&url).send().map_err(|source| ChallengeError::Request {
+			url: url.clone(),
+			source,
+		})?;
+
+		let status = response.status();
+		let body = response.text().map_err(|source| ChallengeError::Request {
+			url: url.clone(),
+			source,
+		})?;
+		self.check_response(url, status, body)
+	}
+
+	/// Turns redirects, login pages and other unsuccessful responses into errors
+	fn check_response(&self, url: String, status: StatusCode, body: String) -> Result<String, ChallengeError> {
+		if status.is_redirection() || is_login_page(&body) {
+			return Err(ChallengeError::SessionExpired {
+				url,
+				session_var: self.session_var.clone(),
+			});
+		}
+
+		if !status.is_success() {
+			return Err(ChallengeError::Status { url, status });
+		}
+
+		Ok(body)
+	}
+
+	/// Checks whether the session is still accepted, the settings page is only available when logged in
+	pub fn validate(&self) -> Result<(), ChallengeError> {
+		self.get("/settings").map(|_| ())
+	}
+
+	/// Downloads the puzzle input, responses that are a web page rather than an input are rejected
+	pub fn input(&self, year: Year, day: Day) -> Result<PuzzleInput, ChallengeError> {
+		let path = format!("/{}/day/{}/input", year, day);
+		let puzzle_input = self.get(&path)?;
+		if puzzle_input.trim().is_empty() || is_html(&puzzle_input) {
+			return Err(ChallengeError::InvalidInput(self.url(&path)));
+		}
+		Ok(puzzle_input)
+	}
+
+	/// Downloads the puzzle description as markdown, including part two once it is unlocked
+	pub fn description(&self, year: Year, day: Day) -> Result<String, ChallengeError> {
+		let path = format!("/{}/day/{}", year, day);
+		description_markdown(&self.get(&path)?).ok_or_else(|| ChallengeError::InvalidInput(self.url(&path)))
+	}
+
+	/// The stars the account earned per day of the event, days that are not unlocked yet are left out
+	pub fn stars(&self, year: Year) -> Result<BTreeMap<Day, u8>, ChallengeError> {
+		let path = format!("/{}", year);
+		let stars = calendar_stars(&self.get(&path)?);
+		if stars.is_empty() {
+			return Err(ChallengeError::InvalidInput(self.url(&path)));
+		}
+		Ok(stars)
+	}
+
+	/// The JSON of a private leaderboard, which may not be requested more than once every 15 minutes
+	pub fn leaderboard(&self, year: Year, id: u64) -> Result<String, ChallengeError> {
+		self.get(&format!("/{}/leaderboard/private/view/{}.json", year, id))
+	}
+}
+
+fn is_html(body: &str) -> bool {
+	let start = body.trim_start().to_lowercase();
+	start.starts_with("<!doctype html") || start.starts_with("<html")
+}
+
+/// Inputs requested without a valid session answer with a request to log in, other pages link to the login form
+fn is_login_page(body: &str) -> bool {
+	body.contains("Please log in")
+		|| (is_html(body) && body.contains("href=\"/auth/login\"") && !body.contains("[Log Out]"))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	pub fn test_check_response() {
+		let client = AocClient::new("session").unwrap();
+		let url = || AOC_URL.to_string();
+
+		assert_eq!(
+			client
+				.check_response(url(), StatusCode::OK, "1\n2\n".to_string())
+				.unwrap(),
+			"1\n2\n"
+		);
+		assert!(matches!(
+			client.check_response(url(), StatusCode::FOUND, String::new()),
+			Err(ChallengeError::SessionExpired { .. })
+		));
+		assert!(matches!(
+			client.check_response(
+				url(),
+				StatusCode::BAD_REQUEST,
+				"Puzzle inputs differ by user.  Please log in to get your puzzle input.\n".to_string()
+			),
+			Err(ChallengeError::SessionExpired { .. })
+		));
+		assert!(matches!(
+			client.check_response(
+				url(),
+				StatusCode::OK,
+				"<!DOCTYPE html><a href=\"/auth/login\">[Log In]</a>".to_string()
+			),
+			Err(ChallengeError::SessionExpired { .. })
+		));
+		assert!(matches!(
+			client.check_response(url(), StatusCode::NOT_FOUND, "404 Not Found".to_string()),
+			Err(ChallengeError::Status { .. })
+		));
+	}
+}
@@ -0,0 +1,268 @@
+use std::collections::BTreeSet;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use super::client::AOC_URL;
+use super::{ChallengeError, InputKey, InputKind, RawPuzzleArgs, Year, PUZZLE_ROOT};
+
+/// Optional config file in the root of the crate, values from the environment take precedence over it
+pub const CONFIG_FILE: &str = "aoc.toml";
+/// Files kept between runs that may not be committed, like caches of the website and the statuses of runs
+pub const STATE_DIRECTORY: &str = "target/aoc";
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+	puzzle_root: Option<PathBuf>,
+	offline: Option<bool>,
+	profile: Option<String>,
+	aoc_url: Option<String>,
+	debug: Option<u8>,
+}
+
+impl ConfigFile {
+	fn load() -> Result<ConfigFile, ChallengeError> {
+		let path = manifest_path(CONFIG_FILE);
+		match fs::read_to_string(&path) {
+			Ok(contents) => {
+				toml::from_str(&contents).map_err(|error| invalid_setting(&path.display().to_string(), error))
+			}
+			Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(ConfigFile::default()),
+			Err(error) => Err(invalid_setting(&path.display().to_string(), error)),
+		}
+	}
+}
+
+fn invalid_setting(setting: &str, message: impl ToString) -> ChallengeError {
+	ChallengeError::InvalidConfig {
+		setting: setting.to_string(),
+		message: message.to_string(),
+	}
+}
+
+/// Profile names become a directory below the puzzle root, so they are limited to a safe set of characters and
+/// lowercase to find the same directory on case-sensitive filesystems
+pub fn parse_profile(value: &str) -> Result<String, String> {
+	if value.is_empty()
+		|| !value
+			.chars()
+			.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_')
+	{
+		return Err(format!(
+			"Profile names may only contain lowercase letters, digits, - and _ ({})",
+			value
+		));
+	}
+	if value.parse::<u16>().is_ok() {
+		return Err(format!(
+			"Profile names can't be a number, those are reserved for years ({})",
+			value
+		));
+	}
+	Ok(value.to_string())
+}
+
+/// Settings shared by all challenges of a run, read from the environment (or `.env`) and `aoc.toml`, overridden by
+/// the CLI
+#[derive(Debug, Clone)]
+pub struct Config {
+	/// Never touch the network, missing inputs are reported instead of downloaded
+	pub offline: bool,
+	/// Directory holding the `<year>/<day>.txt` puzzle inputs
+	pub puzzle_root: PathBuf,
+	/// Account whose inputs are used, stored as `<profile>/<year>/<day>.txt` and downloaded with its own session
+	pub profile: Option<String>,
+	/// Base URL of the website, can point to a local server for testing
+	pub aoc_url: String,
+	/// Arguments handed to every part, read by solutions through `puzzle_args!`
+	pub puzzle_args: RawPuzzleArgs,
+	/// Solve the example input `<year>/<day>.example.txt` instead of the real input
+	pub input: InputKind,
+	/// Highest level of `p!` statements that is printed, nothing is printed at 0
+	pub debug: u8,
+}
+
+impl Default for Config {
+	fn default() -> Self {
+		Config {
+			offline: false,
+			puzzle_root: manifest_path(PUZZLE_ROOT),
+			profile: None,
+			aoc_url: AOC_URL.to_string(),
+			puzzle_args: RawPuzzleArgs::new(),
+			input: InputKind::Real,
+			debug: 0,
+		}
+	}
+}
+
+/// Resolves a path relative to the crate root instead of the working directory, absolute paths are kept as is
+pub fn manifest_path(path: impl AsRef<Path>) -> PathBuf {
+	Path::new(env!("CARGO_MANIFEST_DIR")).join(path)
+}
+
+/// Loads the `.env` file of the crate root, falling back to searching from the working directory
+pub fn load_env() {
+	if dotenv::from_path(manifest_path(".env")).is_err() {
+		dotenv::dotenv().ok();
+	}
+}
+
+fn env_flag(var: &str) -> Option<bool> {
+	env::var(var)
+		.ok()
+		.map(|value| matches!(value.to_lowercase().as_str(), "1" | "true" | "yes"))
+}
+
+impl Config {
+	pub fn from_env() -> Result<Config, ChallengeError> {
+		load_env();
+		let file = ConfigFile::load()?;
+
+		let profile = match env::var("AOC_PROFILE").ok().filter(|profile| !profile.is_empty()) {
+			Some(profile) => Some(parse_profile(&profile).map_err(|message| invalid_setting("AOC_PROFILE", message))?),
+			None => file
+				.profile
+				.map(|profile| parse_profile(&profile).map_err(|message| invalid_setting(CONFIG_FILE, message)))
+				.transpose()?,
+		};
+
+		let debug = match env::var("AOC_DEBUG").ok().filter(|level| !level.is_empty()) {
+			Some(level) => level
+				.trim()
+				.parse()
+				.map_err(|_| invalid_setting("AOC_DEBUG", format!("expected a level from 0 to 255 ({})", level)))?,
+			None => file.debug.unwrap_or(0),
+		};
+
+		Ok(Config {
+			offline: env_flag("AOC_OFFLINE").or(file.offline).unwrap_or(false),
+			puzzle_root: env::var_os("AOC_PUZZLE_ROOT")
+				.map(PathBuf::from)
+				.or(file.puzzle_root)
+				.map_or_else(|| manifest_path(PUZZLE_ROOT), manifest_path),
+			profile,
+			aoc_url: env::var("AOC_URL")
+				.ok()
+				.filter(|url| !url.is_empty())
+				.or(file.aoc_url)
+				.unwrap_or_else(|| AOC_URL.to_string()),
+			puzzle_args: RawPuzzleArgs::new(),
+			input: InputKind::Real,
+			debug,
+		})
+	}
+
+	/// A copy of the config using the inputs and session of another profile
+	pub fn with_profile(&self, profile: Option<&str>) -> Config {
+		Config {
+			profile: profile.map(String::from),
+			..self.clone()
+		}
+	}
+
+	/// Environment variable holding the session cookie of the profile, `AOC_SESSION_<PROFILE>` or `AOC_SESSION`
+	pub fn session_var(&self) -> String {
+		match &self.profile {
+			Some(profile) => format!("{}_{}", SESSION_VAR, profile.to_uppercase().replace('-', "_")),
+			None => SESSION_VAR.to_string(),
+		}
+	}
+
+	/// Whether inputs are stored encrypted as `<year>/<day>.txt.enc`, which is the case when `AOC_INPUT_KEY` is set
+	pub fn encrypts_inputs(&self) -> bool {
+		env::var(INPUT_KEY_VAR).is_ok_and(|key| !key.trim().is_empty())
+	}
+
+	/// The key of encrypted inputs, a malformed key only fails the commands that read or store inputs
+	pub fn input_key(&self) -> Result<Option<InputKey>, ChallengeError> {
+		match env::var(INPUT_KEY_VAR) {
+			Ok(key) if !key.trim().is_empty() => InputKey::from_base64(&key).map(Some),
+			_ => Ok(None),
+		}
+	}
+
+	/// All known profiles, those with a session in the environment and those with a directory of inputs
+	///
+	/// The default account, with `AOC_SESSION` and the inputs directly below the puzzle root, comes first as `None`.
+	pub fn profiles(&self) -> Vec<Option<String>> {
+		let mut has_default = env::var(SESSION_VAR).is_ok_and(|session| !session.is_empty());
+		let mut profiles: BTreeSet<String> = env::vars()
+			.filter(|(_, session)| !session.is_empty())
+			.filter_map(|(var, _)| {
+				parse_profile(&var.strip_prefix(SESSION_VAR)?.strip_prefix('_')?.to_lowercase()).ok()
+			})
+			.collect();
+
+		if let Ok(entries) = fs::read_dir(&self.puzzle_root) {
+			for name in entries
+				.flatten()
+				.filter(|entry| entry.path().is_dir())
+				.filter_map(|entry| entry.file_name().into_string().ok())
+			{
+				if name.parse::<Year>().is_ok() {
+					has_default = true;
+				} else if let Ok(profile) = parse_profile(&name) {
+					profiles.insert(profile);
+				}
+			}
+		}
+
+		has_default
+			.then_some(None)
+			.into_iter()
+			.chain(profiles.into_iter().map(Some))
+			.collect()
+	}
+}
+
+const SESSION_VAR: &str = "AOC_SESSION";
+const INPUT_KEY_VAR: &str = "AOC_INPUT_KEY";
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	pub fn test_manifest_path() {
+		assert_eq!(
+			manifest_path("src/puzzle_inputs"),
+			Path::new(env!("CARGO_MANIFEST_DIR")).join("src").join("puzzle_inputs")
+		);
+		assert_eq!(manifest_path("/srv/inputs"), PathBuf::from("/srv/inputs"));
+	}
+
+	#[test]
+	pub fn test_config_file() {
+		let file: ConfigFile =
+			toml::from_str("puzzle_root = \"../inputs\"\noffline = true\nprofile = \"alice\"\ndebug = 2").unwrap();
+		assert_eq!(file.puzzle_root, Some(PathBuf::from("../inputs")));
+		assert_eq!(file.offline, Some(true));
+		assert_eq!(file.profile.as_deref(), Some("alice"));
+		assert_eq!(file.debug, Some(2));
+		assert!(toml::from_str::<ConfigFile>("puzzle_rot = \"../inputs\"").is_err());
+	}
+
+	#[test]
+	pub fn test_parse_profile() {
+		assert_eq!(parse_profile("work-laptop"), Ok("work-laptop".to_string()));
+		assert!(parse_profile("Alice").is_err());
+		assert!(parse_profile("../alice").is_err());
+		assert!(parse_profile("2023").is_err());
+		assert!(parse_profile("").is_err());
+	}
+
+	#[test]
+	pub fn test_session_var() {
+		let config = Config::default();
+		assert_eq!(config.session_var(), "AOC_SESSION");
+		assert_eq!(
+			config.with_profile(Some("work-laptop")).session_var(),
+			"AOC_SESSION_WORK_LAPTOP"
+		);
+	}
+}
@@ -0,0 +1,103 @@
+use std::fmt;
+use std::path::Path;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+
+use super::ChallengeError;
+
+/// Marks the format of encrypted inputs, followed by the base64 encoded nonce and ciphertext
+const HEADER: &str = "aoc-input-v1:";
+const NONCE_LENGTH: usize = 12;
+
+/// Key used to encrypt puzzle inputs, so they can be committed to a public repository
+#[derive(Clone)]
+pub struct InputKey(Key);
+
+impl fmt::Debug for InputKey {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "InputKey(..)")
+	}
+}
+
+impl InputKey {
+	/// Parses a base64 encoded 32 byte key, e.g. generated with `openssl rand -base64 32`
+	pub fn from_base64(encoded: &str) -> Result<InputKey, ChallengeError> {
+		let bytes = STANDARD
+			.decode(encoded.trim())
+			.map_err(|_| ChallengeError::InvalidKey)?;
+		if bytes.len() != 32 {
+			return Err(ChallengeError::InvalidKey);
+		}
+		Ok(InputKey(*Key::from_slice(&bytes)))
+	}
+
+	pub fn encrypt(&self, plaintext: &str) -> String {
+		let cipher = ChaCha20Poly1305::new(&self.0);
+		let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+		let ciphertext = cipher
+			.encrypt(&nonce, plaintext.as_bytes())
+			.expect("Could not encrypt puzzle input");
+
+		let mut payload = nonce.to_vec();
+		payload.extend(ciphertext);
+		format!("{}{}\n", HEADER, STANDARD.encode(payload))
+	}
+
+	/// Decrypts the contents of an encrypted input file, `path` is only used for error reporting
+	pub fn decrypt(&self, encrypted: &str, path: &Path) -> Result<String, ChallengeError> {
+		let error = || ChallengeError::Decryption(path.to_path_buf());
+		let payload = encrypted
+			.trim()
+			.strip_prefix(HEADER)
+			.and_then(|encoded| STANDARD.decode(encoded).ok())
+			.filter(|payload| payload.len() > NONCE_LENGTH)
+			.ok_or_else(error)?;
+
+		let (nonce, ciphertext) = payload.split_at(NONCE_LENGTH);
+		let plaintext = ChaCha20Poly1305::new(&self.0)
+			.decrypt(Nonce::from_slice(nonce), ciphertext)
+			.map_err(|_| error())?;
+		String::from_utf8(plaintext).map_err(|_| error())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const KEY: &str = "MDEyMzQ1Njc4OWFiY2RlZjAxMjM0NTY3ODlhYmNkZWY=";
+	const OTHER_KEY: &str = "ZmVkY2JhOTg3NjU0MzIxMGZlZGNiYTk4NzY1NDMyMTA=";
+
+	#[test]
+	pub fn test_encrypt_decrypt() {
+		let key = InputKey::from_base64(KEY).unwrap();
+		let encrypted = key.encrypt("1abc2\npqr3stu8vwx\n");
+
+		assert!(encrypted.starts_with(HEADER));
+		assert!(!encrypted.contains("abc"));
+		assert_eq!(
+			key.decrypt(&encrypted, Path::new("01.txt.enc")).unwrap(),
+			"1abc2\npqr3stu8vwx\n"
+		);
+	}
+
+	#[test]
+	pub fn test_decrypt_with_wrong_key() {
+		let encrypted = InputKey::from_base64(KEY).unwrap().encrypt("1abc2");
+		let other_key = InputKey::from_base64(OTHER_KEY).unwrap();
+
+		assert!(matches!(
+			other_key.decrypt(&encrypted, Path::new("01.txt.enc")),
+			Err(ChallengeError::Decryption(_))
+		));
+	}
+
+	#[test]
+	pub fn test_invalid_keys() {
+		assert!(InputKey::from_base64("not base64").is_err());
+		assert!(InputKey::from_base64("c2hvcnQ=").is_err());
+	}
+}
@@ -0,0 +1,75 @@
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use thiserror::Error;
+
+use super::{unlock, Day, Year};
+
+#[derive(Debug, Error)]
+pub enum ChallengeError {
+	#[error("Puzzle input {} is missing and downloading is disabled in offline mode", .0.display())]
+	MissingInput(PathBuf),
+
+	#[error("Example input {} is missing, copy the example from the puzzle description into it", .0.display())]
+	MissingExample(PathBuf),
+
+	#[error("Could not read {setting}: {message}")]
+	InvalidConfig { setting: String, message: String },
+
+	#[error("{0} is not configured in the .env file")]
+	MissingSession(String),
+
+	#[error("The session cookie contains characters that are not allowed in a http header")]
+	InvalidSession,
+
+	#[error("Puzzle input {} is encrypted, but AOC_INPUT_KEY is not configured in the .env file", .0.display())]
+	MissingKey(PathBuf),
+
+	#[error("AOC_INPUT_KEY is not configured, add a key generated with `openssl rand -base64 32` to the .env file")]
+	NoInputKey,
+
+	#[error("AOC_INPUT_KEY must be a base64 encoded 32 byte key")]
+	InvalidKey,
+
+	#[error("Could not decrypt {}, was it encrypted with another key?", .0.display())]
+	Decryption(PathBuf),
+
+	#[error(
+		"The session cookie was rejected by {url}, it has probably expired: log in again and update {session_var}"
+	)]
+	SessionExpired { url: String, session_var: String },
+
+	#[error("{0} did not return the expected content, the response was not saved")]
+	InvalidInput(String),
+
+	#[error("Day {day} of {year} is not unlocked yet, it unlocks in {}", unlock::format_remaining(.remaining))]
+	Locked { year: Year, day: Day, remaining: Duration },
+
+	#[error("Could not read the leaderboard from {location}: {error}")]
+	InvalidLeaderboard {
+		location: String,
+		#[source]
+		error: serde_json::Error,
+	},
+
+	#[error("Solution panicked: {0}")]
+	Panicked(String),
+
+	#[error("Couldn't load {url}, with error: {source}")]
+	Request {
+		url: String,
+		#[source]
+		source: reqwest::Error,
+	},
+
+	#[error("Failed to download {url} with http response code: {status}")]
+	Status { url: String, status: reqwest::StatusCode },
+
+	#[error("Could not access {}: {source}", .path.display())]
+	Io {
+		path: PathBuf,
+		#[source]
+		source: io::Error,
+	},
+}
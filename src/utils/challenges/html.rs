@@ -0,0 +1,119 @@
+use std::collections::BTreeMap;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use super::Day;
+
+lazy_static! {
+	static ref ARTICLE: Regex = Regex::new(r#"(?s)<article class="day-desc">(.*?)</article>"#).unwrap();
+	static ref CODE_BLOCK: Regex = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+	static ref HEADER: Regex = Regex::new(r"(?s)<h2[^>]*>(.*?)</h2>").unwrap();
+	static ref TAG: Regex = Regex::new(r"<[^>]+>").unwrap();
+	static ref BLANK_LINES: Regex = Regex::new(r"\n{3,}").unwrap();
+	static ref CALENDAR_DAY: Regex = Regex::new(r#"class="calendar-day(\d+)([^"]*)""#).unwrap();
+}
+
+fn unescape(text: &str) -> String {
+	text.replace("&lt;", "<")
+		.replace("&gt;", ">")
+		.replace("&quot;", "\"")
+		.replace("&#39;", "'")
+		.replace("&amp;", "&")
+}
+
+fn strip_tags(html: &str) -> String {
+	unescape(&TAG.replace_all(html, ""))
+}
+
+fn inline_markdown(html: &str) -> String {
+	let html = HEADER.replace_all(html, "## $1\n\n");
+	let html = html
+		.replace("</p>", "\n\n")
+		.replace("<li>", "- ")
+		.replace("</li>", "\n")
+		.replace("</ul>", "\n")
+		.replace("<code>", "`")
+		.replace("</code>", "`");
+	let markdown = strip_tags(&html);
+	match BLANK_LINES.replace_all(markdown.trim(), "\n\n").as_ref() {
+		"" => String::new(),
+		markdown => format!("{}\n\n", markdown),
+	}
+}
+
+/// Converts the puzzle descriptions of a day page to markdown, `None` when the page has no description
+///
+/// Only the markup used by the puzzle pages is supported: headers, paragraphs, lists, inline code and example blocks.
+pub fn description_markdown(page: &str) -> Option<String> {
+	let mut markdown = String::new();
+	for article in ARTICLE.captures_iter(page) {
+		let article = &article[1];
+		let mut last = 0;
+		for block in CODE_BLOCK.captures_iter(article) {
+			let range = block.get(0).unwrap().range();
+			markdown.push_str(&inline_markdown(&article[last..range.start]));
+			markdown.push_str(&format!("```\n{}```\n\n", strip_tags(&block[1])));
+			last = range.end;
+		}
+		markdown.push_str(&inline_markdown(&article[last..]));
+	}
+
+	match markdown.trim() {
+		"" => None,
+		markdown => Some(format!("{}\n", markdown)),
+	}
+}
+
+/// Reads the stars of every unlocked day from the calendar of an event page
+///
+/// Days with one star are marked `calendar-complete`, days with both stars `calendar-verycomplete`.
+pub fn calendar_stars(page: &str) -> BTreeMap<Day, u8> {
+	CALENDAR_DAY
+		.captures_iter(page)
+		.filter_map(|captures| {
+			let classes: Vec<&str> = captures[2].split_whitespace().collect();
+			let stars = if classes.contains(&"calendar-verycomplete") {
+				2
+			} else if classes.contains(&"calendar-complete") {
+				1
+			} else {
+				0
+			};
+			Some((captures[1].parse().ok()?, stars))
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	pub fn test_description_markdown() {
+		let page = r#"<main><article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2><p>Sum the <em>calibration values</em> of <code>1abc2</code>:</p>
+<pre><code>1abc2
+<em>pqr3stu8vwx</em>
+</code></pre>
+<ul><li>A &amp; B</li></ul>
+</article></main>"#;
+
+		assert_eq!(
+			description_markdown(page).unwrap(),
+			"## --- Day 1: Trebuchet?! ---\n\nSum the calibration values of `1abc2`:\n\n```\n1abc2\npqr3stu8vwx\n```\n\n- A & B\n"
+		);
+		assert_eq!(description_markdown("<main>Please log in</main>"), None);
+	}
+
+	#[test]
+	pub fn test_calendar_stars() {
+		let page = r#"<pre class="calendar">
+<a aria-label="Day 1, two stars" href="/2023/day/1" class="calendar-day1 calendar-verycomplete">
+<a aria-label="Day 2, one star" href="/2023/day/2" class="calendar-day2 calendar-complete">
+<a aria-label="Day 3" href="/2023/day/3" class="calendar-day3">
+<span aria-hidden="true" class="calendar-day4">
+</pre>"#;
+
+		assert_eq!(calendar_stars(page), BTreeMap::from([(1, 2), (2, 1), (3, 0), (4, 0)]));
+	}
+}
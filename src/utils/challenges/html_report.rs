@@ -0,0 +1,203 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use chrono::Utc;
+
+use super::report::{challenge_header, format_duration};
+use super::{ChallengeError, ChallengeReport, Day, Solution, Year, AOC_URL};
+
+/// The outcome of one challenge of a run, as shown in the HTML report
+#[derive(Debug)]
+pub struct RunResult {
+	pub year: Year,
+	pub day: Day,
+	pub profile: Option<String>,
+	pub result: Result<ChallengeReport, ChallengeError>,
+}
+
+const STYLE: &str = "
+body { font-family: ui-monospace, monospace; background: #0f0f23; color: #cccccc; margin: 2em auto; max-width: 70em; }
+a { color: #009900; text-decoration: none; }
+h1, h2 { color: #ffffff; }
+table { border-collapse: collapse; width: 100%; margin-bottom: 2em; }
+th, td { text-align: left; padding: 0.2em 0.6em; vertical-align: top; }
+th { color: #ffffff; border-bottom: 1px solid #333340; }
+.answer { color: #ffff66; }
+.mismatch, .failed { color: #ff4444; }
+.unsolved { color: #666666; }
+.duration { text-align: right; white-space: nowrap; }
+.bar { background: #009900; height: 0.8em; min-width: 1px; }
+.chart { width: 30%; }
+";
+
+fn escape(text: &str) -> String {
+	text.replace('&', "&amp;")
+		.replace('<', "&lt;")
+		.replace('>', "&gt;")
+		.replace('"', "&quot;")
+}
+
+fn answer_cell(answer: &Solution, is_mismatch: bool, mask_answers: bool) -> String {
+	match answer {
+		Solution::Unsolved => "<td class=\"unsolved\">not yet solved</td>".to_string(),
+		// A wrong answer stays recognizable when the answers are masked
+		_ if is_mismatch && mask_answers => "<td class=\"mismatch\">•••••• (wrong)</td>".to_string(),
+		_ if is_mismatch => format!("<td class=\"mismatch\">{}</td>", escape(&answer.to_string())),
+		_ if mask_answers => "<td class=\"answer\">••••••</td>".to_string(),
+		_ => format!("<td class=\"answer\">{}</td>", escape(&answer.to_string())),
+	}
+}
+
+fn duration_cells(duration: &Duration, slowest: &Duration) -> String {
+	let width = 100.0 * duration.as_secs_f64() / slowest.as_secs_f64().max(f64::EPSILON);
+	format!(
+		"<td class=\"duration\">{}</td><td class=\"chart\"><div class=\"bar\" style=\"width: {:.1}%\"></div></td>",
+		format_duration(duration).trim(),
+		width
+	)
+}
+
+/// Renders the results of a run as a single HTML page without external resources
+///
+/// The bars compare every part with the slowest part of the run, masked answers are hidden so the report can be
+/// shared without spoiling anything.
+pub fn render(results: &[RunResult], mask_answers: bool) -> String {
+	let reports = results.iter().filter_map(|run| run.result.as_ref().ok());
+	let slowest = reports
+		.clone()
+		.flat_map(|report| report.parts.iter().map(|part| *part.duration()))
+		.max()
+		.unwrap_or_default();
+	let total: Duration = reports.map(ChallengeReport::duration).sum();
+	let failures = results.iter().filter(|run| run.result.is_err()).count();
+
+	let mut html = String::new();
+	let _ = write!(
+		html,
+		"<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>Advent of Code run</title>\n<style>{}</style>\n</head>\n<body>\n",
+		STYLE
+	);
+	let _ = writeln!(html, "<h1>Advent of Code run</h1>");
+	let _ = writeln!(
+		html,
+		"<p>{} challenge(s), {} failed, {} in total. Generated {}.</p>",
+		results.len(),
+		failures,
+		format_duration(&total).trim(),
+		Utc::now().format("%Y-%m-%d %H:%M UTC")
+	);
+
+	let mut year = None;
+	for run in results {
+		if year != Some(run.year) {
+			if year.is_some() {
+				html.push_str("</table>\n");
+			}
+			year = Some(run.year);
+			let _ = writeln!(html, "<h2>{}</h2>\n<table>", run.year);
+			html.push_str("<tr><th>Day</th><th>Part</th><th>Answer</th><th>Runtime</th><th></th></tr>\n");
+		}
+
+		let title = match &run.result {
+			Ok(report @ ChallengeReport { title: Some(title), .. }) => format!("{} · {}", report.header(), title),
+			Ok(report) => report.header(),
+			Err(_) => challenge_header(run.year, run.day, run.profile.as_deref()),
+		};
+		let link = format!(
+			"<a href=\"{}/{}/day/{}\">{}</a>",
+			AOC_URL,
+			run.year,
+			run.day,
+			escape(&title)
+		);
+
+		match &run.result {
+			Ok(report) => {
+				for (index, part) in report.parts.iter().enumerate() {
+					let day_cell = match index {
+						0 => format!("<td rowspan=\"{}\">{}</td>", report.parts.len(), link),
+						_ => String::new(),
+					};
+					let _ = writeln!(
+						html,
+						"<tr>{}<td>{}</td>{}{}</tr>",
+						day_cell,
+						escape(&part.title),
+						answer_cell(
+							part.answer(),
+							part.is_mismatch() || !part.variants_agree(),
+							mask_answers
+						),
+						duration_cells(part.duration(), &slowest)
+					);
+				}
+			}
+			Err(error) => {
+				let _ = writeln!(
+					html,
+					"<tr><td>{}</td><td></td><td class=\"failed\" colspan=\"3\">{}</td></tr>",
+					link,
+					escape(&error.to_string())
+				);
+			}
+		}
+	}
+	if year.is_some() {
+		html.push_str("</table>\n");
+	}
+	html.push_str("</body>\n</html>\n");
+	html
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::utils::challenges::{InputKind, PartReport, VariantReport};
+
+	#[test]
+	pub fn test_render() {
+		let report = ChallengeReport {
+			year: 2023,
+			day: 1,
+			profile: None,
+			input: InputKind::Real,
+			title: Some("Trebuchet?!".to_string()),
+			parse_duration: None,
+			parts: vec![PartReport {
+				title: "Part One".to_string(),
+				expected: None,
+				variants: vec![VariantReport {
+					title: "Part One".to_string(),
+					result: Solution::Answer(142),
+					duration: Duration::from_micros(10),
+					memory: None,
+				}],
+			}],
+		};
+		let results = [
+			RunResult {
+				year: 2023,
+				day: 1,
+				profile: None,
+				result: Ok(report),
+			},
+			RunResult {
+				year: 2023,
+				day: 2,
+				profile: None,
+				result: Err(ChallengeError::Panicked("<oops>".to_string())),
+			},
+		];
+
+		let html = render(&results, false);
+		assert!(html.contains("<td class=\"answer\">142</td>"));
+		assert!(html.contains("https://adventofcode.com/2023/day/1\">2023 · Day 1 · Trebuchet?!</a>"));
+		assert!(html.contains("Solution panicked: &lt;oops&gt;"));
+		assert!(!render(&results, true).contains("142"));
+
+		assert_eq!(
+			answer_cell(&Solution::Answer(142), true, true),
+			"<td class=\"mismatch\">•••••• (wrong)</td>"
+		);
+	}
+}
@@ -0,0 +1,164 @@
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use chrono::Utc;
+
+use super::{unlock, AocClient, ChallengeError, Config, Day, InputKind, PuzzleInput, Year};
+
+pub fn puzzle_path(config: &Config, year: Year) -> PathBuf {
+	let mut path: PathBuf = config.puzzle_root.clone();
+	if let Some(profile) = &config.profile {
+		path.push(profile);
+	}
+	path.push(year.to_string());
+	path
+}
+
+pub fn description_path(config: &Config, year: Year, day: Day) -> PathBuf {
+	let mut path = puzzle_path(config, year);
+	match config.encrypts_inputs() {
+		true => path.push(format!("{:02}.md.enc", day)),
+		false => path.push(format!("{:02}.md", day)),
+	}
+	path
+}
+
+pub fn input_path(config: &Config, year: Year, day: Day) -> PathBuf {
+	let mut path = puzzle_path(config, year);
+	path.push(format!("{:02}.txt", day));
+	path
+}
+
+pub fn example_path(config: &Config, year: Year, day: Day) -> PathBuf {
+	let mut path = puzzle_path(config, year);
+	path.push(format!("{:02}.example.txt", day));
+	path
+}
+
+pub fn encrypted_input_path(config: &Config, year: Year, day: Day) -> PathBuf {
+	let mut path = puzzle_path(config, year);
+	path.push(format!("{:02}.txt.enc", day));
+	path
+}
+
+/// Where a downloaded input is saved, encrypted when an input key is configured
+pub fn storage_path(config: &Config, year: Year, day: Day) -> PathBuf {
+	match config.encrypts_inputs() {
+		true => encrypted_input_path(config, year, day),
+		false => input_path(config, year, day),
+	}
+}
+
+pub fn is_stored(config: &Config, year: Year, day: Day) -> bool {
+	input_path(config, year, day).exists() || encrypted_input_path(config, year, day).exists()
+}
+
+fn read(path: &Path) -> Result<Option<String>, ChallengeError> {
+	match fs::read_to_string(path) {
+		Ok(contents) => Ok(Some(contents)),
+		Err(error) if error.kind() == ErrorKind::NotFound => Ok(None),
+		Err(source) => Err(ChallengeError::Io {
+			path: path.to_path_buf(),
+			source,
+		}),
+	}
+}
+
+/// Reads the stored puzzle input, downloading it first when it is missing (unless running offline)
+///
+/// Example inputs are never downloaded, they are copied from the puzzle description by hand.
+/// Plain inputs take precedence over encrypted ones, which are decrypted in memory only.
+pub fn input(year: Year, day: Day, config: &Config) -> Result<PuzzleInput, ChallengeError> {
+	if config.input == InputKind::Example {
+		let path = example_path(config, year, day);
+		return read(&path)?.ok_or(ChallengeError::MissingExample(path));
+	}
+
+	if let Some(puzzle_input) = read(&input_path(config, year, day))? {
+		return Ok(puzzle_input);
+	}
+
+	let path = encrypted_input_path(config, year, day);
+	if let Some(encrypted) = read(&path)? {
+		return match config.input_key()? {
+			Some(key) => key.decrypt(&encrypted, &path),
+			None => Err(ChallengeError::MissingKey(path)),
+		};
+	}
+
+	if config.offline {
+		Err(ChallengeError::MissingInput(storage_path(config, year, day)))
+	} else {
+		download_puzzle(config, year, day)
+	}
+}
+
+/// Downloads and stores the input, error and login pages are rejected by the client and never saved
+///
+/// Puzzles that are not unlocked yet are refused without contacting the server.
+pub fn download_puzzle(config: &Config, year: Year, day: Day) -> Result<PuzzleInput, ChallengeError> {
+	if let Some(remaining) = unlock::time_until_unlock(year, day, Utc::now()) {
+		return Err(ChallengeError::Locked { year, day, remaining });
+	}
+	// A malformed key is better found out before downloading than when saving
+	config.input_key()?;
+
+	let puzzle_input = AocClient::from_config(config)?.input(year, day)?;
+	save_puzzle(config, year, day, &puzzle_input)?;
+	Ok(puzzle_input)
+}
+
+/// Downloads and stores the puzzle description next to the input, encrypted as well when an input key is configured
+pub fn download_description(config: &Config, year: Year, day: Day) -> Result<PathBuf, ChallengeError> {
+	let description = AocClient::from_config(config)?.description(year, day)?;
+	let path = description_path(config, year, day);
+	write(config, year, &path, &description)?;
+	Ok(path)
+}
+
+fn save_puzzle(config: &Config, year: Year, day: Day, puzzle_input: &PuzzleInput) -> Result<(), ChallengeError> {
+	write(config, year, &storage_path(config, year, day), puzzle_input)
+}
+
+fn write(config: &Config, year: Year, path: &Path, contents: &str) -> Result<(), ChallengeError> {
+	let directory = puzzle_path(config, year);
+	fs::create_dir_all(&directory).map_err(|source| ChallengeError::Io {
+		path: directory,
+		source,
+	})?;
+
+	let contents = match config.input_key()? {
+		Some(key) => key.encrypt(contents),
+		None => contents.to_string(),
+	};
+	fs::write(path, contents).map_err(|source| ChallengeError::Io {
+		path: path.to_path_buf(),
+		source,
+	})
+}
+
+/// Writes an encrypted copy of every plain input of the year that doesn't have one yet
+///
+/// The plain inputs are kept, they are ignored by git below the default puzzle root.
+pub fn encrypt_inputs(config: &Config, year: Year) -> Result<Vec<PathBuf>, ChallengeError> {
+	let Some(key) = config.input_key()? else {
+		return Err(ChallengeError::NoInputKey);
+	};
+
+	let mut encrypted = Vec::new();
+	for day in 1..=unlock::days_in_event(year) {
+		let path = encrypted_input_path(config, year, day);
+		if path.exists() {
+			continue;
+		}
+		if let Some(puzzle_input) = read(&input_path(config, year, day))? {
+			fs::write(&path, key.encrypt(&puzzle_input)).map_err(|source| ChallengeError::Io {
+				path: path.clone(),
+				source,
+			})?;
+			encrypted.push(path);
+		}
+	}
+	Ok(encrypted)
+}
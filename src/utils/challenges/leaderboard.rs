@@ -0,0 +1,260 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use colored::Colorize;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer};
+
+use super::{manifest_path, unlock, AocClient, ChallengeError, Config, Day, Year, STATE_DIRECTORY};
+
+/// Private leaderboards may not be requested more often than this
+pub const MIN_REFRESH: Duration = Duration::from_secs(15 * 60);
+
+#[derive(Debug, Deserialize)]
+pub struct Star {
+	pub get_star_ts: i64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Member {
+	pub id: u64,
+	pub name: Option<String>,
+	pub stars: u32,
+	pub local_score: u64,
+	pub last_star_ts: i64,
+	pub completion_day_level: BTreeMap<Day, BTreeMap<u8, Star>>,
+}
+
+impl Member {
+	pub fn display_name(&self) -> String {
+		match &self.name {
+			Some(name) => name.clone(),
+			None => format!("(anonymous user #{})", self.id),
+		}
+	}
+
+	pub fn stars_on(&self, day: Day) -> usize {
+		self.completion_day_level.get(&day).map_or(0, |parts| parts.len())
+	}
+
+	/// Time between the unlock of the puzzle and the star of the part
+	pub fn completion_time(&self, year: Year, day: Day, part: u8) -> Option<Duration> {
+		let star = self.completion_day_level.get(&day)?.get(&part)?;
+		let seconds = star.get_star_ts - unlock::unlock_time(year, day).timestamp();
+		Some(Duration::from_secs(seconds.try_into().ok()?))
+	}
+}
+
+/// The event is a string in the JSON, e.g. `"2023"`
+fn deserialize_event<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Year, D::Error> {
+	let event = String::deserialize(deserializer)?;
+	event
+		.parse()
+		.map_err(|_| D::Error::custom(format!("event is not a year ({})", event)))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Leaderboard {
+	#[serde(deserialize_with = "deserialize_event")]
+	pub event: Year,
+	pub members: BTreeMap<String, Member>,
+}
+
+/// Member names and scores may not end up in the repository, so the cache is kept with the build output
+fn cache_path(year: Year, id: u64) -> PathBuf {
+	manifest_path(STATE_DIRECTORY).join(format!("leaderboard-{}-{}.json", year, id))
+}
+
+fn cache_age(path: &Path) -> Option<Duration> {
+	let modified = fs::metadata(path).and_then(|metadata| metadata.modified()).ok()?;
+	Some(SystemTime::now().duration_since(modified).unwrap_or_default())
+}
+
+impl Leaderboard {
+	pub fn parse(json: &str, location: &str) -> Result<Leaderboard, ChallengeError> {
+		serde_json::from_str(json).map_err(|error| ChallengeError::InvalidLeaderboard {
+			location: location.to_string(),
+			error,
+		})
+	}
+
+	/// Loads the leaderboard from the cache while it is younger than `MIN_REFRESH`, or when offline
+	///
+	/// Returns the leaderboard with the age of the data.
+	pub fn load(config: &Config, year: Year, id: u64) -> Result<(Leaderboard, Duration), ChallengeError> {
+		let path = cache_path(year, id);
+		match cache_age(&path) {
+			Some(age) if age < MIN_REFRESH || config.offline => {
+				let json = fs::read_to_string(&path).map_err(|source| ChallengeError::Io {
+					path: path.clone(),
+					source,
+				})?;
+				return Ok((Leaderboard::parse(&json, &path.display().to_string())?, age));
+			}
+			None if config.offline => return Err(ChallengeError::MissingInput(path)),
+			_ => {}
+		}
+
+		let client = AocClient::from_config(config)?;
+		let json = client.leaderboard(year, id)?;
+		let leaderboard = Leaderboard::parse(&json, &client.url(""))?;
+
+		let directory = manifest_path(STATE_DIRECTORY);
+		fs::create_dir_all(&directory).map_err(|source| ChallengeError::Io {
+			path: directory,
+			source,
+		})?;
+		fs::write(&path, json).map_err(|source| ChallengeError::Io { path, source })?;
+		Ok((leaderboard, Duration::ZERO))
+	}
+
+	pub fn year(&self) -> Year {
+		self.event
+	}
+
+	/// Members ordered by local score, ties go to whoever got their last star first
+	pub fn ranking(&self) -> Vec<&Member> {
+		let mut members: Vec<&Member> = self.members.values().collect();
+		members.sort_by_key(|member| (std::cmp::Reverse(member.local_score), member.last_star_ts, member.id));
+		members
+	}
+
+	/// The most recent day on which any member earned a star
+	pub fn latest_day(&self) -> Option<Day> {
+		self.members
+			.values()
+			.flat_map(|member| member.completion_day_level.keys())
+			.max()
+			.copied()
+	}
+
+	pub fn print(&self, id: u64) {
+		let year = self.year();
+		println!("{}", format!("{} · Leaderboard {}", year, id).bold());
+
+		let ranking = self.ranking();
+		let name_width = ranking
+			.iter()
+			.map(|member| member.display_name().len())
+			.max()
+			.unwrap_or(0);
+		for (rank, member) in ranking.iter().enumerate() {
+			let days: String = (1..=unlock::days_in_event(year))
+				.map(|day| match member.stars_on(day) {
+					2 => "★".bright_yellow(),
+					1 => "★".bright_black(),
+					_ => "·".bright_black(),
+				})
+				.map(|star| star.to_string())
+				.collect();
+
+			println!(
+				"{:>3}) {:>5} {:>3}★  {:<width$}  {}",
+				rank + 1,
+				member.local_score,
+				member.stars,
+				member.display_name(),
+				days,
+				width = name_width
+			);
+		}
+		println!();
+	}
+
+	/// Lists the time it took each member to finish the parts of the day, fastest finisher first
+	pub fn print_day(&self, day: Day) {
+		let year = self.year();
+		println!("{}", format!("{} · Day {}", year, day).cyan());
+
+		let mut members: Vec<&Member> = self
+			.members
+			.values()
+			.filter(|member| member.stars_on(day) > 0)
+			.collect();
+		members.sort_by_key(|member| {
+			(
+				member.completion_time(year, day, 2).unwrap_or(Duration::MAX),
+				member.completion_time(year, day, 1),
+			)
+		});
+		if members.is_empty() {
+			println!(" => {}", "No stars yet".bright_black());
+		}
+
+		let name_width = members
+			.iter()
+			.map(|member| member.display_name().len())
+			.max()
+			.unwrap_or(0);
+		let format_time = |time: Option<Duration>| time.map_or("-".to_string(), |time| unlock::format_remaining(&time));
+		for member in members {
+			println!(
+				" => {:<width$}  {:>16}  {:>16}",
+				member.display_name(),
+				format_time(member.completion_time(year, day, 1)),
+				format_time(member.completion_time(year, day, 2)),
+				width = name_width
+			);
+		}
+		println!();
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::io::{BufRead, BufReader, Write};
+	use std::net::TcpListener;
+	use std::thread;
+
+	use super::*;
+
+	const FIXTURE: &str = include_str!("fixtures/leaderboard.json");
+
+	/// Serves the fixture for a single request, returning the base URL of the server
+	fn serve_fixture() -> String {
+		let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+		let address = listener.local_addr().unwrap();
+		thread::spawn(move || {
+			let (mut stream, _) = listener.accept().unwrap();
+			let mut reader = BufReader::new(stream.try_clone().unwrap());
+			let mut request_line = String::new();
+			reader.read_line(&mut request_line).unwrap();
+			assert!(request_line.starts_with("GET /2023/leaderboard/private/view/1001.json "));
+			write!(
+				stream,
+				"HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+				FIXTURE.len(),
+				FIXTURE
+			)
+			.unwrap();
+		});
+		format!("http://{}", address)
+	}
+
+	#[test]
+	pub fn test_leaderboard_from_fixture_server() {
+		let client = AocClient::new("session").unwrap().with_base_url(&serve_fixture());
+		let leaderboard = Leaderboard::parse(&client.leaderboard(2023, 1001).unwrap(), "fixture").unwrap();
+
+		let ranking: Vec<String> = leaderboard
+			.ranking()
+			.iter()
+			.map(|member| member.display_name())
+			.collect();
+		assert_eq!(ranking, ["Ada", "Grace", "(anonymous user #1003)"]);
+
+		let ada = &leaderboard.members["1001"];
+		assert_eq!(ada.completion_time(2023, 1, 2), Some(Duration::from_secs(1500)));
+		assert_eq!(leaderboard.members["1002"].completion_time(2023, 2, 2), None);
+		assert_eq!(leaderboard.latest_day(), Some(2));
+		assert_eq!(leaderboard.year(), 2023);
+
+		let malformed = FIXTURE.replacen("\"2023\"", "\"next year\"", 1);
+		assert!(matches!(
+			Leaderboard::parse(&malformed, "fixture"),
+			Err(ChallengeError::InvalidLeaderboard { .. })
+		));
+	}
+}
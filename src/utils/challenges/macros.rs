@@ -2,10 +2,10 @@
 macro_rules! preload_challenges {
 	(into $vec:ident) => {
 		preload_challenges! {
-		    into $vec
-		    2015 (
-		        01
-		    )
+			into $vec
+			2015 (
+				01
+			)
 			2023 (
 				01,
 				02,
@@ -52,6 +52,8 @@ macro_rules! reference_challenge_modules {
     }
 }
 
+// Solution modules are referenced from the crate invoking `preload_challenges!`
+#[allow(clippy::crate_in_macro_def)]
 #[macro_export]
 macro_rules! create_challenges {
     (into $vec:ident, $year:literal, ( $($day:literal),+ )) => {
@@ -59,7 +61,7 @@ macro_rules! create_challenges {
             $(
                 $vec.push(
                     Challenge::new(
-                        $year, $day, &*crate::[<year $year day $day>]::PARTS
+                        $year, $day, &*crate::[<year $year day $day>]::SOLVER
                     )
                 );
             )+
@@ -67,9 +69,12 @@ macro_rules! create_challenges {
     }
 }
 
+/// Declares a `PuzzleArgs` struct with defaults, overridden by the arguments passed with `--arg name=value`
+///
+/// A field can have a different default for the example input with `#[example = value]`.
 #[macro_export]
 macro_rules! puzzle_args {
-    ($($field:ident: $type:ty = $default:expr),+) => {
+    ($($(#[example = $example:expr])? $field:ident: $type:ty = $default:expr),+ $(,)?) => {
         #[derive(Debug)]
         struct PuzzleArgs {
             $(
@@ -78,61 +83,105 @@ macro_rules! puzzle_args {
         }
 
         impl PuzzleArgs {
-            // Apologies in advance for this monstrosity >.>
+            /// The runner checks the values with `check_puzzle_args` before solving, so this doesn't panic on a value
+            /// of the wrong type
             fn from(raw_args: &RawPuzzleArgs) -> PuzzleArgs {
-                let mut instance = PuzzleArgs {
+                PuzzleArgs::try_from(raw_args).unwrap_or_else(|error| panic!("{}", error))
+            }
+
+            fn try_from(raw_args: &RawPuzzleArgs) -> Result<PuzzleArgs, PuzzleArgError> {
+                Ok(PuzzleArgs {
                     $(
-                        $field: $default,
+                        $field: match raw_args.get(stringify!($field)) {
+                            Some(entry) => puzzle_args_extract_value!(stringify!($field), entry as $type)?,
+                            None => puzzle_args!(@default raw_args, $default $(, $example)?),
+                        },
                     )+
-                };
-                $(
-                    if let Some(entry) = raw_args.get(stringify!($field)) {
-                        paste! {
-                            instance.$field = puzzle_args_extract_value!(
-                                entry as $type
-                            );
-                        }
-                    }
-                )+
-                instance
+                })
             }
         }
-    }
+
+        /// Shadows the `check_puzzle_args` of the prelude, so `solve!` registers the check of these arguments
+        fn check_puzzle_args(raw_args: &RawPuzzleArgs) -> Result<(), PuzzleArgError> {
+            PuzzleArgs::try_from(raw_args).map(|_| ())
+        }
+    };
+
+    (@default $raw_args:ident, $default:expr) => {
+        $default
+    };
+
+    (@default $raw_args:ident, $default:expr, $example:expr) => {
+        match $raw_args.input {
+            InputKind::Real => $default,
+            InputKind::Example => $example,
+        }
+    };
 }
 
 #[macro_export]
 macro_rules! puzzle_args_extract_value {
-	($entry:ident as String) => {
-		match $entry {
-			PuzzleArg::String(str) => str.to_string(),
-			_ => panic!(),
-		}
-	};
-
-	($entry:ident as u64) => {
-		match $entry {
-			PuzzleArg::Number(nr) => *nr,
-			_ => panic!(),
-		}
-	};
-
-	($entry:ident as bool) => {
-		match $entry {
-			PuzzleArg::Boolean(bool) => *bool,
-			_ => panic!(),
-		}
+	($name:expr, $entry:ident as $type:ty) => {
+		<$type as FromPuzzleArg>::extract($name, $entry)
 	};
 }
 
 #[macro_export]
 macro_rules! solve {
-    ($($func:ident),+) => {
+    (puzzle = $puzzle:expr) => {
         lazy_static! {
-            pub static ref PARTS: Vec<SolutionPart> = vec![
-                $(
-                    SolutionPart::new(stringify!($func), $func),
-                )+
-            ];
+            pub static ref SOLVER: Solver = Solver::from($puzzle).with_args_check(check_puzzle_args);
         }
-    }
+    };
+
+    ($parse:ident => $($func:ident $([$($variant:ident),+])?),+) => {
+        lazy_static! {
+            pub static ref SOLVER: Solver = Solver::new(
+                vec![
+                    $(
+                        solve!(@part $parse, $func $([$($variant),+])?),
+                    )+
+                ],
+            )
+            .with_parser(|input| Box::new($parse(input)))
+            .with_args_check(check_puzzle_args);
+        }
+    };
+
+    // Without a parse function the raw input is handed to the parts, `PuzzleInput::clone` only serves to name its type
+    ($($func:ident $([$($variant:ident),+])?),+) => {
+        lazy_static! {
+            pub static ref SOLVER: Solver = Solver::new(
+                vec![
+                    $(
+                        solve!(@part PuzzleInput::clone, $func $([$($variant),+])?),
+                    )+
+                ],
+            )
+            .with_args_check(check_puzzle_args);
+        }
+    };
+
+    (@part $parse:path, $func:ident) => {
+        SolutionPart::new(stringify!($func), |parsed, args| {
+            let parsed = parsed_input($parse, parsed);
+            $func(parsed, args)
+        })
+    };
+
+    (@part $parse:path, $func:ident [$($variant:ident),+]) => {
+        paste! {
+            SolutionPart::with_variants(
+                stringify!($func),
+                vec![
+                    $(
+                        SolutionVariant::new(stringify!($variant), |parsed, args| {
+                            let parsed = parsed_input($parse, parsed);
+                            [<$func _ $variant>](parsed, args)
+                        }),
+                    )+
+                ],
+            )
+        }
+    };
 }
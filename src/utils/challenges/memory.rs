@@ -0,0 +1,144 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Allocator that counts the allocations of every thread, install it with `#[global_allocator]` to measure parts
+///
+/// The counters are kept per thread, so parts solved in parallel with `--jobs` don't influence each other.
+#[derive(Debug)]
+pub struct CountingAllocator;
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+	static CURRENT: Cell<isize> = const { Cell::new(0) };
+	static PEAK: Cell<isize> = const { Cell::new(0) };
+	static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+}
+
+fn record(size: isize, is_allocation: bool) {
+	if !INSTALLED.load(Ordering::Relaxed) {
+		INSTALLED.store(true, Ordering::Relaxed);
+	}
+
+	// Threads that are shutting down have no counters anymore
+	let _ = CURRENT.try_with(|current| {
+		current.set(current.get() + size);
+		let _ = PEAK.try_with(|peak| peak.set(peak.get().max(current.get())));
+	});
+	if is_allocation {
+		let _ = ALLOCATIONS.try_with(|allocations| allocations.set(allocations.get() + 1));
+	}
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+	unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+		let ptr = System.alloc(layout);
+		if !ptr.is_null() {
+			record(layout.size() as isize, true);
+		}
+		ptr
+	}
+
+	unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+		let ptr = System.alloc_zeroed(layout);
+		if !ptr.is_null() {
+			record(layout.size() as isize, true);
+		}
+		ptr
+	}
+
+	unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+		System.dealloc(ptr, layout);
+		record(-(layout.size() as isize), false);
+	}
+
+	unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+		let new_ptr = System.realloc(ptr, layout, new_size);
+		if !new_ptr.is_null() {
+			record(new_size as isize - layout.size() as isize, true);
+		}
+		new_ptr
+	}
+}
+
+/// Heap usage of a part, the peak is on top of what was allocated before the part started (e.g. the parsed input)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryUsage {
+	pub peak: usize,
+	pub allocations: u64,
+}
+
+impl fmt::Display for MemoryUsage {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{} peak · {} allocations", format_bytes(self.peak), self.allocations)
+	}
+}
+
+/// The state of the counters of the current thread at the start of a measurement
+#[derive(Debug)]
+pub struct Measurement {
+	baseline: isize,
+	allocations: u64,
+}
+
+impl Measurement {
+	/// Starts measuring on the current thread, there is nothing to measure without the `CountingAllocator`
+	pub fn start() -> Option<Measurement> {
+		if !INSTALLED.load(Ordering::Relaxed) {
+			return None;
+		}
+
+		let baseline = CURRENT.with(Cell::get);
+		PEAK.with(|peak| peak.set(baseline));
+		Some(Measurement {
+			baseline,
+			allocations: ALLOCATIONS.with(Cell::get),
+		})
+	}
+
+	pub fn finish(self) -> MemoryUsage {
+		MemoryUsage {
+			peak: (PEAK.with(Cell::get) - self.baseline).max(0) as usize,
+			allocations: ALLOCATIONS.with(Cell::get) - self.allocations,
+		}
+	}
+}
+
+pub fn format_bytes(bytes: usize) -> String {
+	const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+	if bytes < 1024 {
+		return format!("{} B", bytes);
+	}
+
+	let mut size = bytes as f64 / 1024.0;
+	let mut unit = 0;
+	while size >= 1024.0 && unit < UNITS.len() - 1 {
+		size /= 1024.0;
+		unit += 1;
+	}
+	format!("{:.1} {}", size, UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[global_allocator]
+	static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+	#[test]
+	pub fn test_measurement() {
+		let measurement = Measurement::start().unwrap();
+		let numbers: Vec<u64> = (0..1000).collect();
+		drop(numbers);
+		let usage = measurement.finish();
+
+		assert!(usage.peak >= 8000);
+		assert!(usage.allocations >= 1);
+		assert_eq!(format_bytes(512), "512 B");
+		assert_eq!(format_bytes(1536), "1.5 KiB");
+		assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+	}
+}
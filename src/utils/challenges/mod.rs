@@ -1,28 +1,51 @@
-use std::collections::BTreeMap;
+use std::any::Any;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
 use std::path::PathBuf;
 use std::time::{Duration, Instant};
-use std::{env, fmt, fs};
 
-use colored::Colorize;
-use dotenv::dotenv;
-use lazy_static::lazy_static;
-use regex::Regex;
-use reqwest::header::{HeaderMap, HeaderValue};
 use serde::Deserialize;
 
 use super::strings::StringExt;
 
+mod args;
+pub mod calendar;
+mod client;
+mod config;
+mod crypto;
+mod error;
+mod html;
+pub mod html_report;
+pub mod inputs;
+pub mod leaderboard;
 pub mod macros;
+pub mod memory;
 pub mod prelude;
+mod puzzle;
+pub mod readme;
+mod report;
+pub mod scaffold;
+mod scheduler;
+pub mod unlock;
+pub mod watch;
+
+pub use args::{check_puzzle_args, FromPuzzleArg, InputKind, PuzzleArg, PuzzleArgError, RawPuzzleArgs};
+pub use client::{AocClient, AOC_URL};
+pub use config::{manifest_path, parse_profile, Config, CONFIG_FILE, STATE_DIRECTORY};
+pub use crypto::InputKey;
+pub use error::ChallengeError;
+pub use puzzle::Puzzle;
+pub use report::{challenge_header, ChallengeReport, PartReport, VariantReport};
+pub use scheduler::solve_in_order;
 
 pub const PUZZLE_ROOT: &str = "src/puzzle_inputs";
 
-type Day = u8;
-type Year = u16;
+pub type Day = u8;
+pub type Year = u16;
 pub type PuzzleInput = String;
 type PartIdentifier = String;
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, PartialEq)]
 #[serde(untagged)]
 pub enum Solution {
 	Answer(u64),
@@ -44,162 +67,243 @@ impl fmt::Display for Solution {
 	}
 }
 
-type SolutionFn = fn(&PuzzleInput, &RawPuzzleArgs) -> Solution;
+/// Parsed puzzle input, shared between all parts of a challenge
+pub type ParsedInput = Box<dyn Any>;
 
-pub struct SolutionPart {
+type ParseFn = Box<dyn Fn(&PuzzleInput) -> ParsedInput + Send + Sync>;
+type SolutionFn = Box<dyn Fn(&dyn Any, &RawPuzzleArgs) -> Solution + Send + Sync>;
+type ArgsCheckFn = fn(&RawPuzzleArgs) -> Result<(), PuzzleArgError>;
+
+/// One of possibly multiple competing implementations of a part
+pub struct SolutionVariant {
 	pub ident: PartIdentifier,
 	pub solution_fn: SolutionFn,
 }
 
-impl fmt::Debug for SolutionPart {
+impl fmt::Debug for SolutionVariant {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		f.debug_struct("SolutionPart")
+		f.debug_struct("SolutionVariant")
 			.field("ident", &self.ident)
 			.field("solution_fn", &format_args!("{:p}", &self.solution_fn))
 			.finish()
 	}
 }
 
-impl SolutionPart {
+impl SolutionVariant {
 	pub fn title(&self) -> String {
 		self.ident.to_string().titleize()
 	}
 
-	pub fn new(ident: &'static str, solution_fn: SolutionFn) -> SolutionPart {
-		SolutionPart {
+	pub fn new<F>(ident: &'static str, solution_fn: F) -> SolutionVariant
+	where
+		F: Fn(&dyn Any, &RawPuzzleArgs) -> Solution + Send + Sync + 'static,
+	{
+		SolutionVariant {
 			ident: PartIdentifier::from(ident.to_string()),
-			solution_fn,
+			solution_fn: Box::new(solution_fn),
 		}
 	}
 }
 
-#[derive(Debug, Deserialize)]
-#[serde(untagged)]
-pub enum PuzzleArg {
-	Boolean(bool),
-	Number(u64),
-	String(String),
+#[derive(Debug)]
+pub struct SolutionPart {
+	pub ident: PartIdentifier,
+	pub variants: Vec<SolutionVariant>,
+	pub expected: Option<Solution>,
 }
 
-pub type RawPuzzleArgs = BTreeMap<String, PuzzleArg>;
+impl SolutionPart {
+	pub fn title(&self) -> String {
+		self.ident.to_string().titleize()
+	}
 
-#[derive(Debug)]
-pub struct Challenge {
-	pub year: Year,
-	pub day: Day,
-	pub parts: &'static Vec<SolutionPart>,
-}
+	/// Marks the known answer of this part, results are verified against it when running
+	pub fn expecting(mut self, expected: Option<Solution>) -> SolutionPart {
+		self.expected = expected;
+		self
+	}
 
-impl Challenge {
-	fn input(&self) -> PuzzleInput {
-		let mut path: PathBuf = self.puzzle_path();
-		path.push(format!("{:02}.txt", self.day));
+	pub fn new<F>(ident: &'static str, solution_fn: F) -> SolutionPart
+	where
+		F: Fn(&dyn Any, &RawPuzzleArgs) -> Solution + Send + Sync + 'static,
+	{
+		SolutionPart::with_variants(ident, vec![SolutionVariant::new(ident, solution_fn)])
+	}
 
-		match fs::read_to_string(&path) {
-			Ok(puzzle_input) => puzzle_input,
-			Err(_) => self.download_puzzle(),
+	/// Registers several implementations of the same part, which are all run and must agree on the answer
+	pub fn with_variants(ident: &'static str, variants: Vec<SolutionVariant>) -> SolutionPart {
+		SolutionPart {
+			ident: PartIdentifier::from(ident.to_string()),
+			variants,
+			expected: None,
 		}
 	}
+}
 
-	fn download_puzzle(&self) -> PuzzleInput {
-		dotenv().ok();
-
-		let aoc_session = env::var("AOC_SESSION").expect("AOC_SESSION is not configured in the .env file");
-
-		let url = format!("https://adventofcode.com/{}/day/{}/input", self.year, self.day);
-		let client = reqwest::blocking::Client::new();
-		let mut headers = HeaderMap::new();
-		headers.insert(
-			"Cookie",
-			HeaderValue::from_str(&format!("session={}", aoc_session))
-				.expect("Couldn't create the correct Cookie header value"),
-		);
-		let response = client.get(&url).headers(headers).send();
-
-		match response {
-			Ok(response) => {
-				if response.status().is_success() {
-					let puzzle_input = response.text().expect("Failed to parse retrieved input data");
-					self.save_puzzle(&puzzle_input);
-					puzzle_input
-				} else {
-					panic!(
-						"Failed to download input data with http response code: {}",
-						response.status()
-					)
-				}
-			}
-			Err(error) => {
-				panic!("Couldn't load input file from url: {}, with error: {}", url, error)
+/// Retrieves the typed value produced by `parse_fn` from the type-erased parsed input
+pub fn parsed_input<T: 'static>(_parse_fn: fn(&PuzzleInput) -> T, parsed: &dyn Any) -> &T {
+	parsed
+		.downcast_ref::<T>()
+		.expect("Parsed input does not match the return type of the parse function")
+}
+
+pub struct Solver {
+	pub title: Option<String>,
+	pub parse_fn: Option<ParseFn>,
+	pub parts: Vec<SolutionPart>,
+	/// Checks the `--arg` values against the types declared with `puzzle_args!`
+	pub args_check: ArgsCheckFn,
+}
+
+impl fmt::Debug for Solver {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("Solver")
+			.field("title", &self.title)
+			.field(
+				"parse_fn",
+				&self.parse_fn.as_ref().map(|parse_fn| format!("{:p}", parse_fn)),
+			)
+			.field("parts", &self.parts)
+			.field("args_check", &self.args_check)
+			.finish()
+	}
+}
+
+impl Solver {
+	/// Parses the puzzle input once, when there is no parse function the raw input is handed to all parts
+	fn parse(&self, input: &PuzzleInput) -> (ParsedInput, Option<Duration>) {
+		match &self.parse_fn {
+			Some(parse_fn) => {
+				let _span = tracing::info_span!("parse").entered();
+				let start = Instant::now();
+				let parsed = parse_fn(input);
+				(parsed, Some(start.elapsed()))
 			}
+			None => (Box::new(input.clone()), None),
 		}
 	}
 
-	fn puzzle_path(&self) -> PathBuf {
-		let mut path: PathBuf = PathBuf::from(PUZZLE_ROOT);
-		path.push(self.year.to_string());
-		path
+	pub fn with_parser<F>(mut self, parse_fn: F) -> Solver
+	where
+		F: Fn(&PuzzleInput) -> ParsedInput + Send + Sync + 'static,
+	{
+		self.parse_fn = Some(Box::new(parse_fn));
+		self
 	}
 
-	fn save_puzzle(&self, puzzle_input: &PuzzleInput) {
-		let mut path = self.puzzle_path();
-		fs::create_dir_all(&path).expect("Could not create directories");
-		path.push(format!("{:02}.txt", self.day));
-		fs::write(&path, puzzle_input).expect("Could not create file");
+	pub fn with_args_check(mut self, args_check: ArgsCheckFn) -> Solver {
+		self.args_check = args_check;
+		self
 	}
 
-	fn execute<'a>(&self, part: &SolutionPart, input: &'a PuzzleInput, args: &RawPuzzleArgs) -> (Solution, Duration) {
-		let start = Instant::now();
-		let result = (part.solution_fn)(input, args);
-		let duration = start.elapsed();
-		(result, duration)
+	/// Whether the arguments fit the declared types, checked before solving so a mismatch is reported as a usage error
+	pub fn check_args(&self, args: &RawPuzzleArgs) -> Result<(), PuzzleArgError> {
+		(self.args_check)(args)
 	}
 
-	pub fn run(&self) {
-		let input = self.input();
-		let args = RawPuzzleArgs::new();
+	pub fn with_title(mut self, title: Option<&str>) -> Solver {
+		self.title = title.map(String::from);
+		self
+	}
 
-		lazy_static! {
-			static ref WHITESPACE: Regex = Regex::new(r"\s+").unwrap();
+	pub fn new(parts: Vec<SolutionPart>) -> Solver {
+		Solver {
+			title: None,
+			parse_fn: None,
+			parts,
+			args_check: check_puzzle_args,
 		}
+	}
+}
 
-		for part in self.parts {
-			let fmt_header = format!("{} · Day {} · {}", self.year, self.day, part.title(),).cyan();
-			println!("{}", fmt_header);
-
-			let (result, duration) = self.execute(part, &input, &args);
-			self.output(&result, &duration);
-			println!();
-		}
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+	match (payload.downcast_ref::<&str>(), payload.downcast_ref::<String>()) {
+		(Some(message), _) => message.to_string(),
+		(_, Some(message)) => message.clone(),
+		_ => "unknown panic".to_string(),
 	}
+}
+
+#[derive(Debug)]
+pub struct Challenge {
+	pub year: Year,
+	pub day: Day,
+	pub solver: &'static Solver,
+}
 
-	fn output(&self, result: &Solution, duration: &Duration) {
-		let fmt_label = "Answer".normal();
+impl Challenge {
+	pub fn input(&self, config: &Config) -> Result<PuzzleInput, ChallengeError> {
+		inputs::input(self.year, self.day, config)
+	}
 
-		let fmt_text = match result {
-			Solution::Answer(nr) => nr.to_string().green(),
-			Solution::StringAnswer(str) => str.green(),
-			Solution::Unsolved => "[not yet solved]".red(),
-		};
+	pub fn puzzle_path(&self, config: &Config) -> PathBuf {
+		inputs::puzzle_path(config, self.year)
+	}
 
-		let fmt_suffix = if matches!(result, Solution::Answer(_)) {
-			let nanos = duration.as_nanos();
-			if nanos >= 1000000 {
-				format!(" {}ms", num::Integer::div_ceil(&nanos, &1000000)).bright_black()
-			} else if nanos >= 1000 {
-				format!(" {}μs", num::Integer::div_ceil(&nanos, &1000)).bright_black()
-			} else {
-				format!(" {}ns", nanos).bright_black()
-			}
-			.to_string()
-		} else {
-			"".to_string()
-		};
+	fn execute(&self, variant: &SolutionVariant, parsed: &dyn Any, args: &RawPuzzleArgs) -> VariantReport {
+		let _span = tracing::info_span!("part", part = %variant.ident).entered();
+		let measurement = memory::Measurement::start();
+		let start = Instant::now();
+		let result = (variant.solution_fn)(parsed, args);
+		let duration = start.elapsed();
+		// Finished before the title is built, so only the allocations of the part itself are counted
+		let memory = measurement.map(memory::Measurement::finish);
+		VariantReport {
+			title: variant.title(),
+			result,
+			duration,
+			memory,
+		}
+	}
 
-		println!(" => {}: {}{}", fmt_label, fmt_text, fmt_suffix);
+	/// Solves all parts of the challenge without printing anything
+	///
+	/// A panicking solution is reported as an error, so one input that breaks an assumption doesn't abort a whole run.
+	pub fn solve(&self, config: &Config) -> Result<ChallengeReport, ChallengeError> {
+		let _span = tracing::info_span!(
+			"challenge",
+			year = self.year,
+			day = self.day,
+			profile = config.profile.as_deref()
+		)
+		.entered();
+		let input = self.input(config)?;
+		let mut args = config.puzzle_args.clone();
+		args.input = config.input;
+
+		let (parse_duration, parts) = panic::catch_unwind(AssertUnwindSafe(|| {
+			let (parsed, parse_duration) = self.solver.parse(&input);
+			let parts = self
+				.solver
+				.parts
+				.iter()
+				.map(|part| PartReport {
+					title: part.title(),
+					// Expected answers belong to the real input
+					expected: part.expected.clone().filter(|_| config.input == InputKind::Real),
+					variants: part
+						.variants
+						.iter()
+						.map(|variant| self.execute(variant, parsed.as_ref(), &args))
+						.collect(),
+				})
+				.collect();
+			(parse_duration, parts)
+		}))
+		.map_err(|payload| ChallengeError::Panicked(panic_message(payload.as_ref())))?;
+
+		Ok(ChallengeReport {
+			year: self.year,
+			day: self.day,
+			profile: config.profile.clone(),
+			input: config.input,
+			title: self.solver.title.clone(),
+			parse_duration,
+			parts,
+		})
 	}
 
-	pub fn new(year: Year, day: Day, parts: &'static Vec<SolutionPart>) -> Challenge {
-		Challenge { year, day, parts }
+	pub fn new(year: Year, day: Day, solver: &'static Solver) -> Challenge {
+		Challenge { year, day, solver }
 	}
 }
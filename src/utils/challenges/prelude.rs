@@ -3,5 +3,8 @@ pub use lazy_static::lazy_static;
 pub use paste::paste;
 
 pub use self::super::Solution::{Answer, Unsolved};
-pub use self::super::{Challenge, PuzzleArg, PuzzleInput, RawPuzzleArgs, Solution, SolutionPart};
+pub use self::super::{
+	check_puzzle_args, parsed_input, solve_in_order, Challenge, ChallengeReport, FromPuzzleArg, InputKind, ParsedInput,
+	Puzzle, PuzzleArg, PuzzleArgError, PuzzleInput, RawPuzzleArgs, Solution, SolutionPart, SolutionVariant, Solver,
+};
 pub use crate::{p, puzzle_args, puzzle_args_extract_value, solve};
@@ -0,0 +1,108 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use super::{ParsedInput, PuzzleInput, RawPuzzleArgs, Solution, SolutionPart, Solver};
+
+/// Trait based alternative to registering free functions with `solve!`
+///
+/// The puzzle itself is kept alive for as long as the registry, so it can carry state (e.g. configuration or lookup
+/// tables) that is shared between parsing and both parts.
+pub trait Puzzle: Send + Sync {
+	type Input: 'static;
+
+	fn title(&self) -> Option<&str> {
+		None
+	}
+
+	fn expected_part_one(&self) -> Option<Solution> {
+		None
+	}
+
+	fn expected_part_two(&self) -> Option<Solution> {
+		None
+	}
+
+	fn parse(&self, input: &PuzzleInput) -> Self::Input;
+
+	fn part_one(&self, input: &Self::Input, args: &RawPuzzleArgs) -> Solution;
+
+	fn part_two(&self, _input: &Self::Input, _args: &RawPuzzleArgs) -> Solution {
+		Solution::Unsolved
+	}
+}
+
+fn downcast<P: Puzzle>(parsed: &dyn Any) -> &P::Input {
+	parsed
+		.downcast_ref::<P::Input>()
+		.expect("Parsed input does not match the input type of the puzzle")
+}
+
+impl<P: Puzzle + 'static> From<P> for Solver {
+	fn from(puzzle: P) -> Self {
+		let puzzle = Arc::new(puzzle);
+		let (parser, one, two) = (puzzle.clone(), puzzle.clone(), puzzle.clone());
+
+		Solver::new(vec![
+			SolutionPart::new("part_one", move |parsed, args| {
+				one.part_one(downcast::<P>(parsed), args)
+			})
+			.expecting(puzzle.expected_part_one()),
+			SolutionPart::new("part_two", move |parsed, args| {
+				two.part_two(downcast::<P>(parsed), args)
+			})
+			.expecting(puzzle.expected_part_two()),
+		])
+		.with_parser(move |input| Box::new(parser.parse(input)) as ParsedInput)
+		.with_title(puzzle.title())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	struct Sum {
+		offset: u64,
+	}
+
+	impl Puzzle for Sum {
+		type Input = Vec<u64>;
+
+		fn title(&self) -> Option<&str> {
+			Some("Sum")
+		}
+
+		fn expected_part_one(&self) -> Option<Solution> {
+			Some(Solution::Answer(16))
+		}
+
+		fn parse(&self, input: &PuzzleInput) -> Vec<u64> {
+			input.split(',').map(|nr| nr.parse().unwrap()).collect()
+		}
+
+		fn part_one(&self, input: &Vec<u64>, _args: &RawPuzzleArgs) -> Solution {
+			Solution::Answer(input.iter().sum::<u64>() + self.offset)
+		}
+	}
+
+	#[test]
+	pub fn test_puzzle_into_solver() {
+		let solver = Solver::from(Sum { offset: 10 });
+		let (parsed, duration) = solver.parse(&PuzzleInput::from("1,2,3"));
+		let args = RawPuzzleArgs::new();
+
+		assert_eq!(solver.title.as_deref(), Some("Sum"));
+		assert!(duration.is_some());
+		assert_eq!(solver.parts.len(), 2);
+		assert_eq!(solver.parts[0].expected, Some(Solution::Answer(16)));
+		assert_eq!(
+			(solver.parts[0].variants[0].solution_fn)(parsed.as_ref(), &args),
+			Solution::Answer(16)
+		);
+		assert_eq!(solver.parts[1].expected, None);
+		assert_eq!(
+			(solver.parts[1].variants[0].solution_fn)(parsed.as_ref(), &args),
+			Solution::Unsolved
+		);
+	}
+}
@@ -0,0 +1,97 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use super::calendar::PartStatus;
+use super::report::format_duration;
+use super::{unlock, Day, Year};
+
+pub const README: &str = "README.md";
+pub const START_MARKER: &str = "<!-- progress:start -->";
+pub const END_MARKER: &str = "<!-- progress:end -->";
+
+/// Outcome of a registered day over all runs, `runtimes` is empty when the day failed to run
+#[derive(Debug, Default)]
+pub struct DayProgress {
+	pub title: Option<String>,
+	pub parts: Vec<PartStatus>,
+	pub runtimes: Vec<Duration>,
+}
+
+impl DayProgress {
+	pub fn stars(&self) -> usize {
+		self.parts.iter().filter(|part| part.is_solved()).count()
+	}
+
+	pub fn median_runtime(&self) -> Option<Duration> {
+		let mut runtimes = self.runtimes.clone();
+		runtimes.sort();
+		runtimes.get(runtimes.len() / 2).copied()
+	}
+}
+
+fn stars_cell(progress: &DayProgress) -> String {
+	progress.parts.iter().map(PartStatus::symbol).collect()
+}
+
+/// A markdown table per year with the stars and median runtime of every registered day
+pub fn progress_tables(years: &BTreeMap<Year, BTreeMap<Day, DayProgress>>) -> String {
+	let mut markdown = String::new();
+	for (year, days) in years {
+		let stars: usize = days.values().map(DayProgress::stars).sum();
+		markdown.push_str(&format!(
+			"### {}\n\n{}/{} stars\n\n| Day | Puzzle | Stars | Median runtime |\n| --: | --- | :-: | --: |\n",
+			year,
+			stars,
+			usize::from(unlock::days_in_event(*year)) * 2
+		));
+
+		for (day, progress) in days {
+			markdown.push_str(&format!(
+				"| {} | [{}](https://adventofcode.com/{}/day/{}) | {} | {} |\n",
+				day,
+				progress.title.clone().unwrap_or_else(|| format!("Day {}", day)),
+				year,
+				day,
+				stars_cell(progress),
+				progress
+					.median_runtime()
+					.map_or("failed".to_string(), |runtime| format_duration(&runtime)
+						.trim()
+						.to_string())
+			));
+		}
+		markdown.push('\n');
+	}
+	markdown
+}
+
+/// Replaces everything between the progress markers, `None` when the markers are missing
+pub fn replace_section(readme: &str, content: &str) -> Option<String> {
+	let start = readme.find(START_MARKER)? + START_MARKER.len();
+	let end = start + readme[start..].find(END_MARKER)?;
+	Some(format!("{}\n\n{}{}", &readme[..start], content, &readme[end..]))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	pub fn test_stars_cell() {
+		let progress = DayProgress {
+			parts: vec![PartStatus::Verified, PartStatus::Unsolved],
+			..DayProgress::default()
+		};
+		assert_eq!(stars_cell(&progress), "★·");
+	}
+
+	#[test]
+	pub fn test_replace_section() {
+		let readme = format!("# Title\n\n{}\nold\n{}\n\n## Setup\n", START_MARKER, END_MARKER);
+		assert_eq!(
+			replace_section(&readme, "new\n\n").unwrap(),
+			format!("# Title\n\n{}\n\nnew\n\n{}\n\n## Setup\n", START_MARKER, END_MARKER)
+		);
+		assert_eq!(replace_section("# Title\n", "new\n"), None);
+	}
+}
@@ -0,0 +1,173 @@
+use std::time::Duration;
+
+use colored::Colorize;
+
+use super::memory::MemoryUsage;
+use super::{Day, InputKind, Solution, Year};
+
+#[derive(Debug)]
+pub struct VariantReport {
+	pub title: String,
+	pub result: Solution,
+	pub duration: Duration,
+	/// Only measured when the runner is built with the `memory` feature
+	pub memory: Option<MemoryUsage>,
+}
+
+impl VariantReport {
+	fn fmt_measurements(&self) -> String {
+		match &self.memory {
+			Some(memory) => format!("{} · {}", format_duration(&self.duration), memory),
+			None => format_duration(&self.duration),
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct PartReport {
+	pub title: String,
+	pub expected: Option<Solution>,
+	pub variants: Vec<VariantReport>,
+}
+
+impl PartReport {
+	/// The answer of the first variant, other variants are compared against it
+	pub fn answer(&self) -> &Solution {
+		&self.variants[0].result
+	}
+
+	pub fn duration(&self) -> &Duration {
+		&self.variants[0].duration
+	}
+
+	pub fn is_mismatch(&self) -> bool {
+		self.expected.as_ref().is_some_and(|expected| expected != self.answer())
+	}
+
+	pub fn variants_agree(&self) -> bool {
+		self.variants.iter().all(|variant| &variant.result == self.answer())
+	}
+
+	fn print(&self, header: &str) {
+		let fmt_header = format!("{} · {}", header, self.title).cyan();
+		println!("{}", fmt_header);
+
+		let fmt_label = "Answer".normal();
+		let result = self.answer();
+		let is_mismatch = self.is_mismatch();
+
+		let fmt_text = match result {
+			Solution::Answer(_) | Solution::StringAnswer(_) if is_mismatch => result.to_string().red(),
+			Solution::Answer(nr) => nr.to_string().green(),
+			Solution::StringAnswer(str) => str.green(),
+			Solution::Unsolved => "[not yet solved]".red(),
+		};
+
+		let fmt_suffix = if matches!(result, Solution::Answer(_)) {
+			self.variants[0].fmt_measurements().bright_black().to_string()
+		} else {
+			"".to_string()
+		};
+
+		println!(" => {}: {}{}", fmt_label, fmt_text, fmt_suffix);
+
+		if let Some(expected) = self.expected.as_ref().filter(|_| is_mismatch) {
+			println!(" => {}: {}", "Expected".normal(), expected.to_string().yellow());
+		}
+
+		if self.variants.len() > 1 {
+			self.print_variants();
+		}
+	}
+
+	fn print_variants(&self) {
+		let width = self
+			.variants
+			.iter()
+			.map(|variant| variant.title.len())
+			.max()
+			.unwrap_or(0);
+
+		for variant in &self.variants {
+			let fmt_suffix = if &variant.result != self.answer() {
+				format!(" {} (disagrees)", variant.result).red()
+			} else {
+				"".normal()
+			};
+
+			println!(
+				"    {:<width$} {}{}",
+				variant.title,
+				variant.fmt_measurements().bright_black(),
+				fmt_suffix,
+				width = width
+			);
+		}
+	}
+}
+
+#[derive(Debug)]
+pub struct ChallengeReport {
+	pub year: Year,
+	pub day: Day,
+	pub profile: Option<String>,
+	pub input: InputKind,
+	pub title: Option<String>,
+	pub parse_duration: Option<Duration>,
+	pub parts: Vec<PartReport>,
+}
+
+impl ChallengeReport {
+	/// `<year> · Day <day>`, followed by the profile when the input of a specific account was used and whether the
+	/// example input was used
+	pub fn header(&self) -> String {
+		let header = challenge_header(self.year, self.day, self.profile.as_deref());
+		match self.input {
+			InputKind::Real => header,
+			InputKind::Example => format!("{} · example", header),
+		}
+	}
+
+	/// Time spent on parsing and the first variant of every part
+	pub fn duration(&self) -> Duration {
+		self.parse_duration.unwrap_or_default() + self.parts.iter().map(|part| *part.duration()).sum::<Duration>()
+	}
+
+	pub fn print(&self) {
+		let header = self.header();
+		if let Some(title) = &self.title {
+			println!("{}", format!("{} · {}", header, title).bold());
+			println!();
+		}
+
+		if let Some(duration) = &self.parse_duration {
+			let fmt_header = format!("{} · Parse", header).cyan();
+			println!("{}", fmt_header);
+			println!(" => {}:{}", "Parsed".normal(), format_duration(duration).bright_black());
+			println!();
+		}
+
+		for part in &self.parts {
+			part.print(&header);
+			println!();
+		}
+	}
+}
+
+pub fn challenge_header(year: Year, day: Day, profile: Option<&str>) -> String {
+	match profile {
+		Some(profile) => format!("{} · Day {} · {}", year, day, profile),
+		None => format!("{} · Day {}", year, day),
+	}
+}
+
+pub fn format_duration(duration: &Duration) -> String {
+	let nanos = duration.as_nanos();
+	if nanos >= 1000000 {
+		format!(" {}ms", num::Integer::div_ceil(&nanos, &1000000))
+	} else if nanos >= 1000 {
+		format!(" {}μs", num::Integer::div_ceil(&nanos, &1000))
+	} else {
+		format!(" {}ns", nanos)
+	}
+}
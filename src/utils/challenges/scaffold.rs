@@ -0,0 +1,106 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use super::{manifest_path, ChallengeError, Day, Year};
+
+const BOILERPLATE: &str = "src/solutions/boilerplate.rs";
+const REGISTRY: &str = "src/utils/challenges/macros.rs";
+
+lazy_static! {
+	static ref REGISTERED_DAYS: Regex = Regex::new(r"(?s)preload_challenges! \{\s*into \$vec\s*(.*?)\n\t\t\}").unwrap();
+	static ref YEAR: Regex = Regex::new(r"(\d{4})\s*\(([^)]*)\)").unwrap();
+}
+
+pub fn solution_path(year: Year, day: Day) -> PathBuf {
+	let mut path = manifest_path("src/solutions");
+	path.push(year.to_string());
+	path.push(format!("{:02}.rs", day));
+	path
+}
+
+fn io_error(path: &Path) -> impl FnOnce(std::io::Error) -> ChallengeError + '_ {
+	move |source| ChallengeError::Io {
+		path: path.to_path_buf(),
+		source,
+	}
+}
+
+/// Copies the boilerplate to the solution file of the day, an existing solution is left untouched
+pub fn create_solution(year: Year, day: Day) -> Result<Option<PathBuf>, ChallengeError> {
+	let path = solution_path(year, day);
+	if path.exists() {
+		return Ok(None);
+	}
+
+	let directory = path.parent().expect("Solution files are stored in a year directory");
+	fs::create_dir_all(directory).map_err(io_error(directory))?;
+	let boilerplate = manifest_path(BOILERPLATE);
+	fs::copy(&boilerplate, &path).map_err(io_error(&boilerplate))?;
+	Ok(Some(path))
+}
+
+/// Adds the day to `preload_challenges!`, returns `false` when it was registered already
+pub fn register_challenge(year: Year, day: Day) -> Result<bool, ChallengeError> {
+	let path = manifest_path(REGISTRY);
+	let source = fs::read_to_string(&path).map_err(io_error(&path))?;
+	match register_in_source(&source, year, day) {
+		Some(source) => fs::write(&path, source).map(|_| true).map_err(io_error(&path)),
+		None => Ok(false),
+	}
+}
+
+/// Rewrites the list of registered days of `preload_challenges!`, sorted by year and day
+fn register_in_source(source: &str, year: Year, day: Day) -> Option<String> {
+	let list = REGISTERED_DAYS.captures(source)?.get(1)?;
+
+	let mut registered: BTreeMap<Year, BTreeSet<Day>> = BTreeMap::new();
+	for captures in YEAR.captures_iter(list.as_str()) {
+		let days = captures[2].split(',').filter_map(|day| day.trim().parse::<Day>().ok());
+		registered.entry(captures[1].parse().ok()?).or_default().extend(days);
+	}
+	if !registered.entry(year).or_default().insert(day) {
+		return None;
+	}
+
+	let list_source: Vec<String> = registered
+		.iter()
+		.map(|(year, days)| {
+			let days: Vec<String> = days.iter().map(|day| format!("\t\t\t\t{:02}", day)).collect();
+			format!("{} (\n{}\n\t\t\t)", year, days.join(",\n"))
+		})
+		.collect();
+
+	let mut source = source.to_string();
+	source.replace_range(list.range(), &list_source.join("\n\t\t\t"));
+	Some(source)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	pub fn test_register_in_source() {
+		let source =
+			"macro_rules! preload_challenges {\n\t(into $vec:ident) => {\n\t\tpreload_challenges! {\n\t\t\tinto \
+		              $vec\n\t\t\t2023 (\n\t\t\t\t01,\n\t\t\t\t03\n\t\t\t)\n\t\t}\n\t};\n}\n";
+
+		let registered = register_in_source(source, 2023, 2).unwrap();
+		assert!(registered.contains("2023 (\n\t\t\t\t01,\n\t\t\t\t02,\n\t\t\t\t03\n\t\t\t)\n\t\t}"));
+
+		let registered = register_in_source(&registered, 2024, 1).unwrap();
+		assert!(registered.contains("\t\t\t)\n\t\t\t2024 (\n\t\t\t\t01\n\t\t\t)\n\t\t}"));
+		assert_eq!(register_in_source(&registered, 2023, 3), None);
+
+		// The registry itself must stay in a format that can be rewritten
+		let registry = include_str!("macros.rs");
+		assert_eq!(register_in_source(registry, 2015, 1), None);
+		assert!(
+			register_in_source(registry, 2015, 2).is_some_and(|source| source.contains("\t\t\t\t01,\n\t\t\t\t02\n"))
+		);
+	}
+}
@@ -0,0 +1,46 @@
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+use super::{Challenge, ChallengeError, ChallengeReport, Config};
+
+/// Solves the challenges, each with its own config, on a pool of `jobs` threads
+///
+/// Reports are handed to `on_report` as soon as all challenges before it have finished, so the output keeps the
+/// order of `challenges` regardless of which thread finishes first.
+pub fn solve_in_order<'a, F>(challenges: &[(&'a Challenge, &'a Config)], jobs: usize, mut on_report: F)
+where
+	F: FnMut(&'a Challenge, &'a Config, Result<ChallengeReport, ChallengeError>),
+{
+	let next = AtomicUsize::new(0);
+	let (sender, receiver) = mpsc::channel();
+
+	thread::scope(|scope| {
+		for _ in 0..jobs.clamp(1, challenges.len().max(1)) {
+			let sender = sender.clone();
+			let next = &next;
+			scope.spawn(move || loop {
+				let index = next.fetch_add(1, Ordering::Relaxed);
+				let Some((challenge, config)) = challenges.get(index) else {
+					break;
+				};
+				if sender.send((index, challenge.solve(config))).is_err() {
+					break;
+				}
+			});
+		}
+		drop(sender);
+
+		let mut finished = BTreeMap::new();
+		let mut reported = 0;
+		for (index, report) in receiver {
+			finished.insert(index, report);
+			while let Some(report) = finished.remove(&reported) {
+				let (challenge, config) = challenges[reported];
+				on_report(challenge, config, report);
+				reported += 1;
+			}
+		}
+	});
+}
@@ -0,0 +1,87 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Datelike, FixedOffset, TimeZone, Utc};
+
+use super::{Day, Year};
+
+pub const FIRST_YEAR: Year = 2015;
+
+/// Puzzles unlock at midnight US Eastern time, which doesn't observe daylight saving time in December
+const UNLOCK_OFFSET: i32 = -5 * 3600;
+
+fn unlock_zone() -> FixedOffset {
+	FixedOffset::east_opt(UNLOCK_OFFSET).expect("Unlock offset is out of range")
+}
+
+/// Number of puzzles in the event, from 2025 onwards the event runs for 12 days
+pub fn days_in_event(year: Year) -> Day {
+	if year >= 2025 {
+		12
+	} else {
+		25
+	}
+}
+
+/// The most recent year with an event, which is the current year as soon as it is December in US Eastern time
+pub fn latest_year(now: DateTime<Utc>) -> Year {
+	let now = now.with_timezone(&unlock_zone());
+	match now.month() {
+		12 => now.year() as Year,
+		_ => now.year() as Year - 1,
+	}
+}
+
+/// The years that have (or are about to have) an event, up to and including the current year
+pub fn valid_years(now: DateTime<Utc>) -> std::ops::RangeInclusive<Year> {
+	FIRST_YEAR..=now.with_timezone(&unlock_zone()).year() as Year
+}
+
+pub fn unlock_time(year: Year, day: Day) -> DateTime<Utc> {
+	unlock_zone()
+		.with_ymd_and_hms(year.into(), 12, day.into(), 0, 0, 0)
+		.single()
+		.expect("Unlock time is not a valid date")
+		.with_timezone(&Utc)
+}
+
+/// Time left until the puzzle unlocks, `None` once it is available
+pub fn time_until_unlock(year: Year, day: Day, now: DateTime<Utc>) -> Option<Duration> {
+	(unlock_time(year, day) - now)
+		.to_std()
+		.ok()
+		.filter(|remaining| !remaining.is_zero())
+}
+
+pub fn format_remaining(remaining: &Duration) -> String {
+	let seconds = remaining.as_secs();
+	let (days, hours, minutes, seconds) = (seconds / 86400, seconds / 3600 % 24, seconds / 60 % 60, seconds % 60);
+	match days {
+		0 => format!("{:02}:{:02}:{:02}", hours, minutes, seconds),
+		1 => format!("1 day {:02}:{:02}:{:02}", hours, minutes, seconds),
+		_ => format!("{} days {:02}:{:02}:{:02}", days, hours, minutes, seconds),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	pub fn test_unlock_time() {
+		let unlock = Utc.with_ymd_and_hms(2023, 12, 5, 5, 0, 0).unwrap();
+		assert_eq!(unlock_time(2023, 5), unlock);
+		assert_eq!(
+			time_until_unlock(2023, 5, unlock - chrono::Duration::seconds(90)),
+			Some(Duration::from_secs(90))
+		);
+		assert_eq!(time_until_unlock(2023, 5, unlock), None);
+
+		assert_eq!(latest_year(Utc.with_ymd_and_hms(2023, 12, 1, 4, 59, 59).unwrap()), 2022);
+		assert_eq!(latest_year(Utc.with_ymd_and_hms(2023, 12, 1, 5, 0, 0).unwrap()), 2023);
+		assert_eq!(
+			valid_years(Utc.with_ymd_and_hms(2026, 10, 18, 12, 0, 0).unwrap()),
+			2015..=2026
+		);
+		assert_eq!(format_remaining(&Duration::from_secs(90061)), "1 day 01:01:01");
+	}
+}
@@ -0,0 +1,151 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, SystemTime};
+use std::{env, fs, thread};
+
+use colored::Colorize;
+
+use super::scaffold::solution_path;
+use super::{inputs, manifest_path, ChallengeReport, Config, Day, InputKind, Year};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+type Answers = BTreeMap<String, String>;
+
+/// Writes the answers of a run as `title<TAB>answer` lines, so a watching process can compare runs
+pub fn write_answers(report: &ChallengeReport, path: &Path) {
+	let answers: String = report
+		.parts
+		.iter()
+		.map(|part| format!("{}\t{}\n", part.title, part.answer()))
+		.collect();
+	fs::write(path, answers).expect("Could not write answers file");
+}
+
+fn read_answers(path: &Path) -> Answers {
+	fs::read_to_string(path)
+		.unwrap_or_default()
+		.lines()
+		.filter_map(|line| line.split_once('\t'))
+		.map(|(title, answer)| (title.to_string(), answer.to_string()))
+		.collect()
+}
+
+/// The solution file, the puzzle input and any example inputs (e.g. `05.example.txt`) of the day
+fn watched_files(year: Year, day: Day, config: &Config) -> Vec<PathBuf> {
+	let prefix = format!("{:02}.", day);
+	let mut files = vec![solution_path(year, day)];
+	if let Ok(entries) = fs::read_dir(inputs::puzzle_path(config, year)) {
+		files.extend(
+			entries
+				.filter_map(|entry| entry.ok())
+				.map(|entry| entry.path())
+				.filter(|path| {
+					path.file_name()
+						.and_then(|name| name.to_str())
+						.is_some_and(|name| name.starts_with(&prefix))
+				}),
+		);
+	}
+	files.sort();
+	files
+}
+
+fn modification_times(files: &[PathBuf]) -> Vec<Option<SystemTime>> {
+	files
+		.iter()
+		.map(|file| fs::metadata(file).and_then(|metadata| metadata.modified()).ok())
+		.collect()
+}
+
+fn rebuild() -> bool {
+	let mut command = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()));
+	command
+		.args(["build", "--bin", "runner", "--manifest-path"])
+		.arg(manifest_path("Cargo.toml"));
+	if !cfg!(debug_assertions) {
+		command.arg("--release");
+	}
+	command.status().is_ok_and(|status| status.success())
+}
+
+fn rerun(runner: &Path, year: Year, day: Day, config: &Config, answers_path: &Path) -> bool {
+	let mut command = Command::new(runner);
+	command
+		.arg(year.to_string())
+		.arg(day.to_string())
+		.arg("--answers")
+		.arg(answers_path)
+		.arg("--puzzle-root")
+		.arg(&config.puzzle_root);
+	if config.offline {
+		command.arg("--offline");
+	}
+	if let Some(profile) = &config.profile {
+		command.arg("--profile").arg(profile);
+	}
+	if config.input == InputKind::Example {
+		command.arg("--example");
+	}
+	if config.debug > 0 {
+		command.arg(format!("--debug={}", config.debug));
+	}
+	for (name, value) in config.puzzle_args.iter() {
+		command.arg("--arg").arg(format!("{}={}", name, value));
+	}
+	command.status().is_ok_and(|status| status.success())
+}
+
+fn print_answer_diff(previous: &Answers, current: &Answers) {
+	println!("{}", "Compared to the previous run".cyan());
+	for (title, answer) in current {
+		match previous.get(title) {
+			Some(previous) if previous == answer => {
+				println!(" => {}: {} {}", title, answer, "(unchanged)".bright_black())
+			}
+			Some(previous) => println!(" => {}: {} → {}", title, previous.yellow(), answer.green()),
+			None => println!(" => {}: {} {}", title, answer.green(), "(new)".bright_black()),
+		}
+	}
+	println!();
+}
+
+/// Rebuilds and reruns a single day whenever its solution, input or example files change
+///
+/// The day doesn't have to be registered in the running binary yet, e.g. when it was just scaffolded.
+pub fn watch(year: Year, day: Day, config: &Config) -> ! {
+	let answers_path = env::temp_dir().join(format!("aoc-{}-{:02}-answers.txt", year, day));
+	// Resolved before the first rebuild, afterwards the path of this process points to a replaced file
+	let runner = env::current_exe().expect("Could not locate the runner executable");
+	let mut previous: Option<Answers> = None;
+
+	loop {
+		println!("{}", "Rebuilding...".bright_black());
+		let _ = fs::remove_file(&answers_path);
+
+		if rebuild() && rerun(&runner, year, day, config, &answers_path) {
+			let current = read_answers(&answers_path);
+			if let Some(previous) = &previous {
+				print_answer_diff(previous, &current);
+			}
+			previous = Some(current);
+		}
+
+		// Taken after the run, so an input downloaded by it doesn't count as a change
+		let files = watched_files(year, day, config);
+		let times = modification_times(&files);
+		println!(
+			"{}",
+			format!("Watching {} file(s) for changes", files.len()).bright_black()
+		);
+
+		loop {
+			thread::sleep(POLL_INTERVAL);
+			let current_files = watched_files(year, day, config);
+			if current_files != files || modification_times(&current_files) != times {
+				break;
+			}
+		}
+	}
+}
@@ -1,29 +1,155 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use colored::Colorize;
+pub use tracing::Level;
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(0);
+
+/// Sets the highest level of `p!` statements that is printed, from `--debug` or `AOC_DEBUG`
+pub fn set_verbosity(verbosity: u8) {
+	VERBOSITY.store(verbosity, Ordering::Relaxed);
+}
+
+pub fn verbosity() -> u8 {
+	VERBOSITY.load(Ordering::Relaxed)
+}
+
+/// A value printed by `p!`, expressions are shown together with their source
+#[derive(Debug)]
+pub enum Value {
+	Literal(String),
+	Expr(&'static str, String),
+}
+
+impl Value {
+	fn colored(&self) -> String {
+		match self {
+			Value::Literal(literal) => literal.to_string(),
+			Value::Expr(source, value) => format!(
+				"{}{} {}{}",
+				source.bright_black(),
+				":".bright_black(),
+				value.bright_cyan(),
+				",".bright_black()
+			),
+		}
+	}
+
+	fn plain(&self) -> String {
+		match self {
+			Value::Literal(literal) => literal.to_string(),
+			Value::Expr(source, value) => format!("{}: {},", source, value),
+		}
+	}
+}
+
+/// Without a tracing level, statements of verbosity 1 are debug events and higher ones trace events
+fn event_level(verbosity: u8, level: Option<Level>) -> Level {
+	level.unwrap_or(if verbosity <= 1 { Level::DEBUG } else { Level::TRACE })
+}
+
+/// Whether a `p!` statement is printed, checked before its values are formatted so disabled statements cost nothing
+pub fn is_enabled(verbosity: u8, level: Option<Level>) -> bool {
+	if !tracing::dispatcher::has_been_set() {
+		return verbosity <= self::verbosity();
+	}
+
+	match event_level(verbosity, level) {
+		Level::ERROR => tracing::enabled!(Level::ERROR),
+		Level::WARN => tracing::enabled!(Level::WARN),
+		Level::INFO => tracing::enabled!(Level::INFO),
+		Level::DEBUG => tracing::enabled!(Level::DEBUG),
+		Level::TRACE => tracing::enabled!(Level::TRACE),
+	}
+}
+
+/// Prints the values of `p!` to stderr, or emits them as an event of the current part when tracing is enabled
+/// (`--trace`)
+pub fn emit(verbosity: u8, level: Option<Level>, values: &[Value]) {
+	if !tracing::dispatcher::has_been_set() {
+		let values: Vec<String> = values.iter().map(Value::colored).collect();
+		eprintln!("{}", values.join(" "));
+		return;
+	}
+
+	let values: Vec<String> = values.iter().map(Value::plain).collect();
+	let message = values.join(" ");
+	match event_level(verbosity, level) {
+		Level::ERROR => tracing::error!("{}", message),
+		Level::WARN => tracing::warn!("{}", message),
+		Level::INFO => tracing::info!("{}", message),
+		Level::DEBUG => tracing::debug!("{}", message),
+		Level::TRACE => tracing::trace!("{}", message),
+	}
+}
+
 // Loosely based on built-in std::dbg macro
 // See: https://doc.rust-lang.org/src/std/macros.rs.html#286-308
 #[macro_export]
 macro_rules! p {
     () => {
-        println!();
+        if $crate::utils::debug::is_enabled(1, None) {
+            $crate::utils::debug::emit(1, None, &[])
+        }
     };
 
     (arg $val:literal $(,)?) => {
-        print!("{} ", $val)
+        $crate::utils::debug::Value::Literal(format!("{}", $val))
     };
 
     (arg $val:expr $(,)?) => {
-        print!(
-            "{}{} {}{} ",
-            stringify!($val).bright_black(),
-            ":".bright_black(),
-            format!("{:#?}", $val).bright_cyan(),
-            ",".bright_black(),
-        )
+        $crate::utils::debug::Value::Expr(stringify!($val), format!("{:#?}", $val))
+    };
+
+    (level = $verbosity:literal, $($val:expr),+ $(,)?) => {
+        if $crate::utils::debug::is_enabled($verbosity, None) {
+            $crate::utils::debug::emit($verbosity, None, &[$(p!(arg $val)),+])
+        }
+    };
+
+    (level = $level:ident, $($val:expr),+ $(,)?) => {
+        if $crate::utils::debug::is_enabled(1, Some($crate::utils::debug::Level::$level)) {
+            $crate::utils::debug::emit(1, Some($crate::utils::debug::Level::$level), &[$(p!(arg $val)),+])
+        }
     };
 
     ($($val:expr),+ $(,)?) => {
-        $(
-            p!(arg $val);
-        )+
-        println!();
+        if $crate::utils::debug::is_enabled(1, None) {
+            $crate::utils::debug::emit(1, None, &[$(p!(arg $val)),+])
+        }
     };
 }
+
+#[cfg(test)]
+mod tests {
+	use std::fmt;
+
+	use super::*;
+
+	/// Fails the test when a disabled statement formats its value anyway
+	struct Unformattable;
+
+	impl fmt::Debug for Unformattable {
+		fn fmt(&self, _f: &mut fmt::Formatter) -> fmt::Result {
+			panic!("Disabled p! statements should not format their values");
+		}
+	}
+
+	#[test]
+	pub fn test_values() {
+		assert_eq!(p!(arg "sum").plain(), "sum");
+		assert_eq!(p!(arg 1 + 2).plain(), "1 + 2: 3,");
+	}
+
+	#[test]
+	pub fn test_verbosity() {
+		set_verbosity(1);
+		assert!(is_enabled(1, None) && !is_enabled(2, None));
+		p!(level = 2, Unformattable);
+
+		set_verbosity(0);
+		assert!(!is_enabled(1, None));
+		p!(Unformattable);
+		p!(level = INFO, Unformattable);
+	}
+}
@@ -0,0 +1,244 @@
+use std::cell::RefCell;
+use std::fmt;
+use std::fmt::{Debug, Display};
+use std::ops::AddAssign;
+
+use num::{Integer, NumCast};
+
+use super::{GridAccess, GridEntry, GridPoint, GridValue};
+
+type Cell<Y, X, V> = Option<(GridPoint<Y, X>, RefCell<GridValue<V>>)>;
+
+/// Row-major grid backed by a `Vec`, for rectangular inputs starting at `(0, 0)`
+///
+/// It has the API of `Grid`, but looking up a point is an index calculation instead of a tree lookup. Points are kept
+/// next to their values so entries can be handed out the same way.
+#[derive(Debug, Clone)]
+pub struct DenseGrid<X, Y, V> {
+	cells: Vec<Cell<Y, X, V>>,
+	width: usize,
+	height: usize,
+}
+
+impl<X, Y, V> Default for DenseGrid<X, Y, V> {
+	fn default() -> Self {
+		DenseGrid {
+			cells: Vec::new(),
+			width: 0,
+			height: 0,
+		}
+	}
+}
+
+impl<X, Y, V> DenseGrid<X, Y, V>
+where
+	X: AddAssign + Copy + Debug + Integer + NumCast,
+	Y: AddAssign + Copy + Debug + Integer + NumCast,
+	V: Display,
+{
+	/// Reserves room for a grid of the given size, which saves moving the rows while it is filled
+	pub fn with_size(height: usize, width: usize) -> Self {
+		let mut cells = Vec::new();
+		cells.resize_with(height * width, || None);
+		DenseGrid { cells, width, height }
+	}
+
+	pub fn width(&self) -> usize {
+		self.width
+	}
+
+	pub fn height(&self) -> usize {
+		self.height
+	}
+
+	fn index(&self, (y, x): GridPoint<Y, X>) -> Option<usize> {
+		let (y, x) = (y.to_usize()?, x.to_usize()?);
+		(y < self.height && x < self.width).then_some(y * self.width + x)
+	}
+
+	fn coordinate<T: NumCast>(index: usize) -> T {
+		T::from(index).expect("Grid is too large for its coordinates")
+	}
+
+	pub fn column(&self, x: X) -> impl Iterator<Item = Option<&RefCell<GridValue<V>>>> + '_ {
+		(0..self.height).map(move |y| self.get((Self::coordinate(y), x)))
+	}
+
+	pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = Option<&RefCell<GridValue<V>>>> + '_> + '_ {
+		(0..self.width).map(|x| self.column(Self::coordinate(x)))
+	}
+
+	pub fn row(&self, y: Y) -> impl Iterator<Item = Option<&RefCell<GridValue<V>>>> + '_ {
+		(0..self.width).map(move |x| self.get((y, Self::coordinate(x))))
+	}
+
+	pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = Option<&RefCell<GridValue<V>>>> + '_> + '_ {
+		(0..self.height).map(|y| self.row(Self::coordinate(y)))
+	}
+
+	pub fn min_x(&self) -> X {
+		X::zero()
+	}
+
+	pub fn max_x(&self) -> X {
+		Self::coordinate(self.width.checked_sub(1).expect("Grid is empty"))
+	}
+
+	pub fn min_y(&self) -> Y {
+		Y::zero()
+	}
+
+	pub fn max_y(&self) -> Y {
+		Self::coordinate(self.height.checked_sub(1).expect("Grid is empty"))
+	}
+
+	pub fn iter(&self) -> impl Iterator<Item = GridEntry<'_, Y, X, V>> {
+		self.cells.iter().flatten().map(|(point, value)| (point, value))
+	}
+
+	pub fn get_point(&self, point: GridPoint<Y, X>) -> Option<GridEntry<'_, Y, X, V>> {
+		let (point, value) = self.cells[self.index(point)?].as_ref()?;
+		Some((point, value))
+	}
+
+	pub fn get(&self, point: GridPoint<Y, X>) -> Option<&RefCell<GridValue<V>>> {
+		self.get_point(point).map(|(_, value)| value)
+	}
+
+	/// Grows the grid when the point lies outside of it, points before `(0, 0)` are not supported
+	pub fn set(&mut self, point: GridPoint<Y, X>, value: RefCell<GridValue<V>>) {
+		let (y, x) = (
+			point.0.to_usize().expect("Dense grids start at (0, 0)"),
+			point.1.to_usize().expect("Dense grids start at (0, 0)"),
+		);
+		if x >= self.width {
+			self.widen(x + 1);
+		}
+		if y >= self.height {
+			self.height = y + 1;
+			self.cells.resize_with(self.height * self.width, || None);
+		}
+
+		let index = y * self.width + x;
+		self.cells[index] = Some((point, value));
+	}
+
+	fn widen(&mut self, width: usize) {
+		let mut cells = Vec::with_capacity(self.height * width);
+		let mut rows = std::mem::take(&mut self.cells).into_iter();
+		for _ in 0..self.height {
+			cells.extend(rows.by_ref().take(self.width));
+			cells.resize_with(cells.len() + width - self.width, || None);
+		}
+		self.cells = cells;
+		self.width = width;
+	}
+
+	pub fn adjacent_iter_indices(&self, point: GridPoint<Y, X>) -> impl Iterator<Item = GridPoint<Y, X>> + '_ {
+		GridAccess::adjacent_iter_indices(self, point)
+	}
+
+	pub fn adjacent_iter(&self, point: GridPoint<Y, X>) -> impl Iterator<Item = GridEntry<'_, Y, X, V>> + '_ {
+		self.adjacent_iter_indices(point)
+			.filter_map(move |adj_point| self.get_point(adj_point))
+	}
+}
+
+impl<X, Y, V> GridAccess<X, Y, V> for DenseGrid<X, Y, V>
+where
+	X: AddAssign + Copy + Debug + Integer + NumCast,
+	Y: AddAssign + Copy + Debug + Integer + NumCast,
+	V: Display,
+{
+	fn with_size(height: usize, width: usize) -> Self {
+		DenseGrid::with_size(height, width)
+	}
+
+	fn get(&self, point: GridPoint<Y, X>) -> Option<&RefCell<GridValue<V>>> {
+		DenseGrid::get(self, point)
+	}
+
+	fn set(&mut self, point: GridPoint<Y, X>, value: RefCell<GridValue<V>>) {
+		DenseGrid::set(self, point, value)
+	}
+
+	fn iter<'a>(&'a self) -> impl Iterator<Item = GridEntry<'a, Y, X, V>>
+	where
+		X: 'a,
+		Y: 'a,
+		V: 'a,
+	{
+		DenseGrid::iter(self)
+	}
+
+	fn adjacent_iter<'a>(&'a self, point: GridPoint<Y, X>) -> impl Iterator<Item = GridEntry<'a, Y, X, V>> + 'a
+	where
+		X: 'a,
+		Y: 'a,
+		V: 'a,
+	{
+		DenseGrid::adjacent_iter(self, point)
+	}
+}
+
+impl<X, Y, V> Display for DenseGrid<X, Y, V>
+where
+	X: AddAssign + Copy + Debug + Integer + NumCast,
+	Y: AddAssign + Copy + Debug + Integer + NumCast,
+	V: Display,
+{
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		for row in self.rows() {
+			for value in row {
+				match value {
+					Some(value) => write!(f, "{}", value.borrow())?,
+					None => write!(f, ".")?,
+				}
+			}
+			writeln!(f)?;
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::utils::grids::Grid;
+
+	#[test]
+	pub fn test_same_as_grid() {
+		let mut grid: Grid<isize, isize, char> = Grid::default();
+		let mut dense: DenseGrid<isize, isize, char> = DenseGrid::default();
+		for (y, line) in ["ab.", "c*d", "efg"].iter().enumerate() {
+			for (x, char) in line.chars().enumerate().filter(|(_, char)| *char != '.') {
+				grid.set((y as isize, x as isize), RefCell::new(char));
+				dense.set((y as isize, x as isize), RefCell::new(char));
+			}
+		}
+
+		let values = |entries: Vec<GridEntry<'_, isize, isize, char>>| -> Vec<(GridPoint<isize, isize>, char)> {
+			entries
+				.into_iter()
+				.map(|(point, value)| (*point, *value.borrow()))
+				.collect()
+		};
+		assert_eq!(values(dense.iter().collect()), values(grid.iter().collect()));
+		assert_eq!(
+			values(dense.adjacent_iter((1, 1)).collect()),
+			values(grid.adjacent_iter((1, 1)).collect())
+		);
+		assert_eq!(values(dense.adjacent_iter((0, 0)).collect()).len(), 3);
+		assert!(dense.get((-1, 0)).is_none() && dense.get((0, 2)).is_none());
+
+		let chars = |cells: Vec<Option<&RefCell<char>>>| -> String {
+			cells
+				.into_iter()
+				.map(|cell| cell.map_or('.', |cell| *cell.borrow()))
+				.collect()
+		};
+		assert_eq!(chars(dense.row(1).collect()), chars(grid.row(1).collect()));
+		assert_eq!(chars(dense.column(2).collect()), ".dg");
+		assert_eq!(dense.to_string(), "ab.\nc*d\nefg\n");
+	}
+}
@@ -1,4 +1,3 @@
-use std::array::IntoIter;
 use std::cell::RefCell;
 use std::collections::BTreeMap;
 use std::fmt;
@@ -7,10 +6,13 @@ use std::ops::{AddAssign, Range};
 
 use num::Integer;
 
+use super::GridAccess;
+
 pub type GridPoint<Y, X> = (Y, X);
 pub type GridValue<V> = V;
+pub type GridEntry<'a, Y, X, V> = (&'a GridPoint<Y, X>, &'a RefCell<GridValue<V>>);
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct Grid<X, Y, V> {
 	points: BTreeMap<GridPoint<Y, X>, RefCell<GridValue<V>>>,
 }
@@ -65,11 +67,11 @@ where
 		self.points.iter().map(|((_, x), _)| *x)
 	}
 
-	fn min_x(&self) -> X {
+	pub fn min_x(&self) -> X {
 		self.xs().min().unwrap()
 	}
 
-	fn max_x(&self) -> X {
+	pub fn max_x(&self) -> X {
 		self.xs().max().unwrap()
 	}
 
@@ -77,19 +79,19 @@ where
 		self.points.iter().map(|((y, _), _)| *y)
 	}
 
-	fn min_y(&self) -> Y {
+	pub fn min_y(&self) -> Y {
 		self.ys().min().unwrap()
 	}
 
-	fn max_y(&self) -> Y {
+	pub fn max_y(&self) -> Y {
 		self.ys().max().unwrap()
 	}
 
-	pub fn iter(&self) -> impl Iterator<Item = (&GridPoint<Y, X>, &RefCell<GridValue<V>>)> {
+	pub fn iter(&self) -> impl Iterator<Item = GridEntry<'_, Y, X, V>> {
 		self.points.iter()
 	}
 
-	pub fn get_point(&self, point: GridPoint<Y, X>) -> Option<(&GridPoint<Y, X>, &RefCell<GridValue<V>>)> {
+	pub fn get_point(&self, point: GridPoint<Y, X>) -> Option<GridEntry<'_, Y, X, V>> {
 		self.points.get_key_value(&point)
 	}
 
@@ -101,37 +103,56 @@ where
 		self.points.insert(point, value);
 	}
 
-	pub fn adjacent_iter_indices(&self, (y, x): GridPoint<Y, X>) -> impl Iterator<Item = GridPoint<Y, X>> + '_ {
-		let x1 = X::one();
-		let y1 = Y::one();
-		let adjacent = [
-			(y - y1, x - x1),
-			(y - y1, x),
-			(y - y1, x + x1),
-			(y, x - x1),
-			(y, x + x1),
-			(y + y1, x - x1),
-			(y + y1, x),
-			(y + y1, x + x1),
-		];
-		IntoIter::filter_map(adjacent.into_iter(), move |point| {
-			if self.points.contains_key(&point) {
-				Some(point)
-			} else {
-				None
-			}
-		})
+	pub fn adjacent_iter_indices(&self, point: GridPoint<Y, X>) -> impl Iterator<Item = GridPoint<Y, X>> + '_ {
+		GridAccess::adjacent_iter_indices(self, point)
 	}
 
-	pub fn adjacent_iter(
-		&self,
-		point: GridPoint<Y, X>,
-	) -> impl Iterator<Item = (&GridPoint<Y, X>, &RefCell<GridValue<V>>)> + '_ {
+	pub fn adjacent_iter(&self, point: GridPoint<Y, X>) -> impl Iterator<Item = GridEntry<'_, Y, X, V>> + '_ {
 		self.adjacent_iter_indices(point)
 			.filter_map(move |adj_point| self.get_point(adj_point))
 	}
 }
 
+impl<X, Y, V> GridAccess<X, Y, V> for Grid<X, Y, V>
+where
+	X: AddAssign + Copy + Debug + Integer,
+	Y: AddAssign + Copy + Debug + Integer,
+	V: Display,
+{
+	/// The points are kept in a tree, which has no room to reserve
+	fn with_size(_height: usize, _width: usize) -> Self {
+		Grid {
+			points: BTreeMap::new(),
+		}
+	}
+
+	fn get(&self, point: GridPoint<Y, X>) -> Option<&RefCell<GridValue<V>>> {
+		Grid::get(self, point)
+	}
+
+	fn set(&mut self, point: GridPoint<Y, X>, value: RefCell<GridValue<V>>) {
+		Grid::set(self, point, value)
+	}
+
+	fn iter<'a>(&'a self) -> impl Iterator<Item = GridEntry<'a, Y, X, V>>
+	where
+		X: 'a,
+		Y: 'a,
+		V: 'a,
+	{
+		Grid::iter(self)
+	}
+
+	fn adjacent_iter<'a>(&'a self, point: GridPoint<Y, X>) -> impl Iterator<Item = GridEntry<'a, Y, X, V>> + 'a
+	where
+		X: 'a,
+		Y: 'a,
+		V: 'a,
+	{
+		Grid::adjacent_iter(self, point)
+	}
+}
+
 impl<X, Y, V> Display for Grid<X, Y, V>
 where
 	X: AddAssign + Copy + Debug + Integer + Ord,
@@ -0,0 +1,182 @@
+use std::fmt::{Debug, Display};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::ops::AddAssign;
+use std::path::Path;
+use std::time::Duration;
+
+use num::Integer;
+
+use super::{Grid, StaticGrid};
+
+pub type Rgb = [u8; 3];
+
+/// Background of points that are missing from a `Grid`
+pub const BACKGROUND: Rgb = [15, 15, 35];
+
+/// An RGB image of a grid, every cell is drawn as a square of `scale` by `scale` pixels
+#[derive(Debug, Clone, PartialEq)]
+pub struct Image {
+	pub width: usize,
+	pub height: usize,
+	pixels: Vec<u8>,
+}
+
+impl Image {
+	/// Draws rows of cells, rows shorter than the longest row are padded with the background
+	pub fn from_cells(cells: &[Vec<Rgb>], scale: usize) -> Image {
+		let scale = scale.max(1);
+		let columns = cells.iter().map(Vec::len).max().unwrap_or(0);
+		let mut pixels = Vec::with_capacity(columns * cells.len() * scale * scale * 3);
+		for row in cells {
+			for _ in 0..scale {
+				for color in row.iter().chain(std::iter::repeat_n(&BACKGROUND, columns - row.len())) {
+					for _ in 0..scale {
+						pixels.extend_from_slice(color);
+					}
+				}
+			}
+		}
+
+		Image {
+			width: columns * scale,
+			height: cells.len() * scale,
+			pixels,
+		}
+	}
+
+	pub fn from_grid<X, Y, V>(grid: &Grid<X, Y, V>, scale: usize, color: impl Fn(&V) -> Rgb) -> Image
+	where
+		X: AddAssign + Copy + Debug + Integer,
+		Y: AddAssign + Copy + Debug + Integer,
+		V: Display,
+	{
+		if grid.iter().next().is_none() {
+			return Image::from_cells(&[], scale);
+		}
+
+		let cells: Vec<Vec<Rgb>> = grid
+			.rows()
+			.map(|row| {
+				row.map(|value| value.map_or(BACKGROUND, |value| color(&value.borrow())))
+					.collect()
+			})
+			.collect();
+		Image::from_cells(&cells, scale)
+	}
+
+	pub fn from_static_grid<T>(grid: &StaticGrid<T>, scale: usize, color: impl Fn(&T) -> Rgb) -> Image {
+		let cells: Vec<Vec<Rgb>> = grid.grid.iter().map(|row| row.iter().map(&color).collect()).collect();
+		Image::from_cells(&cells, scale)
+	}
+
+	/// Writes a binary PPM, which needs no encoder but is rather large
+	pub fn write_ppm(&self, path: impl AsRef<Path>) -> io::Result<()> {
+		let mut writer = BufWriter::new(File::create(path)?);
+		write!(writer, "P6\n{} {}\n255\n", self.width, self.height)?;
+		writer.write_all(&self.pixels)?;
+		writer.flush()
+	}
+
+	pub fn write_png(&self, path: impl AsRef<Path>) -> io::Result<()> {
+		let writer = BufWriter::new(File::create(path)?);
+		let mut encoder = png::Encoder::new(writer, self.width as u32, self.height as u32);
+		encoder.set_color(png::ColorType::Rgb);
+		encoder.set_depth(png::BitDepth::Eight);
+		encoder
+			.write_header()
+			.and_then(|mut writer| writer.write_image_data(&self.pixels))
+			.map_err(io::Error::other)
+	}
+
+	/// Writes a PNG or PPM, depending on the extension of the path
+	pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+		let path = path.as_ref();
+		match path.extension().and_then(|extension| extension.to_str()) {
+			Some("png") => self.write_png(path),
+			Some("ppm") => self.write_ppm(path),
+			_ => Err(io::Error::new(
+				io::ErrorKind::InvalidInput,
+				format!("Images can only be saved as .png or .ppm ({})", path.display()),
+			)),
+		}
+	}
+}
+
+/// Writes the images as a looping animation, they are reduced to 256 colours and must all have the same size
+pub fn write_gif(path: impl AsRef<Path>, images: &[Image], delay: Duration) -> io::Result<()> {
+	let Some(first) = images.first() else {
+		return Err(io::Error::new(io::ErrorKind::InvalidInput, "An animation needs frames"));
+	};
+	let size = |image: &Image| -> io::Result<(u16, u16)> {
+		match (image.width.try_into(), image.height.try_into()) {
+			(Ok(width), Ok(height)) if image.width == first.width && image.height == first.height => {
+				Ok((width, height))
+			}
+			_ => Err(io::Error::new(
+				io::ErrorKind::InvalidInput,
+				format!(
+					"Frames must all be {}x{} and at most 65535 pixels wide and high, got {}x{}",
+					first.width, first.height, image.width, image.height
+				),
+			)),
+		}
+	};
+
+	let (width, height) = size(first)?;
+	let writer = BufWriter::new(File::create(path)?);
+	let mut encoder = gif::Encoder::new(writer, width, height, &[]).map_err(io::Error::other)?;
+	encoder.set_repeat(gif::Repeat::Infinite).map_err(io::Error::other)?;
+	for image in images {
+		let (width, height) = size(image)?;
+		let mut frame = gif::Frame::from_rgb_speed(width, height, &image.pixels, 10);
+		frame.delay = (delay.as_millis() / 10).clamp(1, u16::MAX as u128) as u16;
+		encoder.write_frame(&frame).map_err(io::Error::other)?;
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use std::fs;
+
+	use super::*;
+
+	#[test]
+	pub fn test_ragged_rows() {
+		let white = [255; 3];
+		let image = Image::from_cells(&[vec![white], vec![white, white]], 1);
+		assert_eq!((image.width, image.height), (2, 2));
+		assert_eq!(image.pixels.len(), 2 * 2 * 3);
+		assert_eq!(&image.pixels[3..6], &BACKGROUND);
+	}
+
+	#[test]
+	pub fn test_export() {
+		let grid = StaticGrid {
+			grid: vec![vec![0u8, 1], vec![1, 0]],
+		};
+		let image = Image::from_static_grid(&grid, 2, |value| [*value * 255; 3]);
+		assert_eq!((image.width, image.height), (4, 4));
+		assert_eq!(&image.pixels[..12], &[0, 0, 0, 0, 0, 0, 255, 255, 255, 255, 255, 255]);
+
+		let directory = std::env::temp_dir().join(format!("aoc-image-{}", std::process::id()));
+		fs::create_dir_all(&directory).unwrap();
+		image.save(directory.join("grid.ppm")).unwrap();
+		image.save(directory.join("grid.png")).unwrap();
+		write_gif(
+			directory.join("grid.gif"),
+			&[image.clone(), image.clone()],
+			Duration::from_millis(100),
+		)
+		.unwrap();
+
+		assert!(fs::read(directory.join("grid.ppm"))
+			.unwrap()
+			.starts_with(b"P6\n4 4\n255\n"));
+		assert!(fs::read(directory.join("grid.png")).unwrap().starts_with(b"\x89PNG"));
+		assert!(fs::read(directory.join("grid.gif")).unwrap().starts_with(b"GIF89a"));
+		assert!(image.save(directory.join("grid.jpg")).is_err());
+		fs::remove_dir_all(directory).unwrap();
+	}
+}
@@ -1,5 +1,59 @@
+use std::cell::RefCell;
+
+use num::Integer;
+
+mod dense_grid;
 mod grid;
+mod image;
 mod static_grid;
 
-pub use grid::{Grid, GridPoint, GridValue};
+pub use dense_grid::DenseGrid;
+pub use grid::{Grid, GridEntry, GridPoint, GridValue};
+pub use image::{write_gif, Image, Rgb, BACKGROUND};
 pub use static_grid::StaticGrid;
+
+/// The access `Grid` and `DenseGrid` have in common, so a solution can be written once for both
+pub trait GridAccess<X, Y, V> {
+	/// An empty grid for `height` rows of `width` points, so filling it row by row doesn't have to grow it
+	fn with_size(height: usize, width: usize) -> Self
+	where
+		Self: Sized;
+
+	fn get(&self, point: GridPoint<Y, X>) -> Option<&RefCell<GridValue<V>>>;
+
+	fn set(&mut self, point: GridPoint<Y, X>, value: RefCell<GridValue<V>>);
+
+	fn iter<'a>(&'a self) -> impl Iterator<Item = GridEntry<'a, Y, X, V>>
+	where
+		X: 'a,
+		Y: 'a,
+		V: 'a;
+
+	fn adjacent_iter<'a>(&'a self, point: GridPoint<Y, X>) -> impl Iterator<Item = GridEntry<'a, Y, X, V>> + 'a
+	where
+		X: 'a,
+		Y: 'a,
+		V: 'a;
+
+	/// The points around `(y, x)` that are part of the grid, diagonals included
+	fn adjacent_iter_indices<'a>(&'a self, (y, x): GridPoint<Y, X>) -> impl Iterator<Item = GridPoint<Y, X>> + 'a
+	where
+		X: Copy + Integer + 'a,
+		Y: Copy + Integer + 'a,
+		V: 'a,
+	{
+		let x1 = X::one();
+		let y1 = Y::one();
+		let adjacent = [
+			(y - y1, x - x1),
+			(y - y1, x),
+			(y - y1, x + x1),
+			(y, x - x1),
+			(y, x + x1),
+			(y + y1, x - x1),
+			(y + y1, x),
+			(y + y1, x + x1),
+		];
+		adjacent.into_iter().filter(move |point| self.get(*point).is_some())
+	}
+}
@@ -6,7 +6,7 @@ impl Math {
 		let mut factors: Vec<usize> = Vec::new();
 
 		// Divide in 2 before uneven
-		while n % 2 == 0 {
+		while n.is_multiple_of(2) {
 			factors.push(2);
 			n /= 2
 		}
@@ -14,7 +14,7 @@ impl Math {
 		// n is now uneven, next step (i = 3, 5, 7, ...)
 		let mut i = 3;
 		while i * i <= n {
-			while n % i == 0 {
+			while n.is_multiple_of(i) {
 				factors.push(i);
 				n /= i;
 			}
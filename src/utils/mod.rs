@@ -1,5 +1,7 @@
+pub mod benchmark;
 pub mod challenges;
 pub mod debug;
 pub mod grids;
 pub mod math;
 pub mod strings;
+pub mod visualize;
@@ -0,0 +1,254 @@
+use std::collections::BTreeMap;
+use std::fmt::{Debug, Display};
+use std::io::{self, Write};
+use std::ops::AddAssign;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::thread;
+use std::time::Duration;
+
+use colored::{Color, Colorize};
+use num::{Integer, NumCast, ToPrimitive};
+
+use super::grids::{DenseGrid, Grid, Image, Rgb, StaticGrid, BACKGROUND};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static FRAMES: Mutex<Vec<Frame>> = Mutex::new(Vec::new());
+
+/// Starts recording the frames pushed by solutions, set by the runner with `--visualize`
+pub fn enable() {
+	ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Solutions only build frames when this is set, so a visualization costs nothing in normal runs
+pub fn is_enabled() -> bool {
+	ENABLED.load(Ordering::Relaxed)
+}
+
+pub fn push(frame: Frame) {
+	if is_enabled() {
+		FRAMES.lock().unwrap_or_else(PoisonError::into_inner).push(frame);
+	}
+}
+
+/// Takes the frames recorded so far, e.g. after a challenge has been solved
+pub fn take() -> Vec<Frame> {
+	std::mem::take(&mut *FRAMES.lock().unwrap_or_else(PoisonError::into_inner))
+}
+
+fn key<Y: ToPrimitive, X: ToPrimitive>((y, x): &(Y, X)) -> (i64, i64) {
+	(
+		y.to_i64().expect("Point does not fit in a frame"),
+		x.to_i64().expect("Point does not fit in a frame"),
+	)
+}
+
+/// The cells of a grid at one moment, shared by all frames that only differ in what they highlight
+#[derive(Debug, Default)]
+pub struct Snapshot {
+	origin: (i64, i64),
+	rows: Vec<Vec<String>>,
+}
+
+impl<X, Y, V> From<&Grid<X, Y, V>> for Snapshot
+where
+	X: AddAssign + Copy + Debug + Integer + ToPrimitive,
+	Y: AddAssign + Copy + Debug + Integer + ToPrimitive,
+	V: Display,
+{
+	fn from(grid: &Grid<X, Y, V>) -> Self {
+		if grid.iter().next().is_none() {
+			return Snapshot::default();
+		}
+
+		let rows = grid
+			.rows()
+			.map(|row| {
+				row.map(|value| value.map_or(".".to_string(), |value| value.borrow().to_string()))
+					.collect()
+			})
+			.collect();
+		Snapshot {
+			origin: key(&(grid.min_y(), grid.min_x())),
+			rows,
+		}
+	}
+}
+
+impl<X, Y, V> From<&DenseGrid<X, Y, V>> for Snapshot
+where
+	X: AddAssign + Copy + Debug + Integer + NumCast,
+	Y: AddAssign + Copy + Debug + Integer + NumCast,
+	V: Display,
+{
+	fn from(grid: &DenseGrid<X, Y, V>) -> Self {
+		let rows = grid
+			.rows()
+			.map(|row| {
+				row.map(|value| value.map_or(".".to_string(), |value| value.borrow().to_string()))
+					.collect()
+			})
+			.collect();
+		Snapshot { origin: (0, 0), rows }
+	}
+}
+
+impl<T: Display> From<&StaticGrid<T>> for Snapshot {
+	fn from(grid: &StaticGrid<T>) -> Self {
+		Snapshot {
+			origin: (0, 0),
+			rows: grid
+				.grid
+				.iter()
+				.map(|row| row.iter().map(T::to_string).collect())
+				.collect(),
+		}
+	}
+}
+
+/// A snapshot with highlighted points, the highlight added last wins when points overlap
+#[derive(Debug, Clone)]
+pub struct Frame {
+	snapshot: Arc<Snapshot>,
+	highlights: BTreeMap<(i64, i64), Color>,
+	caption: Option<String>,
+}
+
+impl Frame {
+	pub fn new(snapshot: &Arc<Snapshot>) -> Frame {
+		Frame {
+			snapshot: Arc::clone(snapshot),
+			highlights: BTreeMap::new(),
+			caption: None,
+		}
+	}
+
+	pub fn highlight<Y: ToPrimitive, X: ToPrimitive>(mut self, point: (Y, X), color: Color) -> Frame {
+		self.highlights.insert(key(&point), color);
+		self
+	}
+
+	pub fn path<'a, Y, X>(mut self, points: impl IntoIterator<Item = &'a (Y, X)>, color: Color) -> Frame
+	where
+		Y: ToPrimitive + 'a,
+		X: ToPrimitive + 'a,
+	{
+		self.highlights
+			.extend(points.into_iter().map(|point| (key(point), color)));
+		self
+	}
+
+	pub fn caption(mut self, caption: impl Into<String>) -> Frame {
+		self.caption = Some(caption.into());
+		self
+	}
+
+	/// The highlight of the cell at `(y, x)`, counted from the top left of the snapshot
+	fn highlight_at(&self, y: usize, x: usize) -> Option<Color> {
+		let (origin_y, origin_x) = self.snapshot.origin;
+		self.highlights
+			.get(&(origin_y + y as i64, origin_x + x as i64))
+			.copied()
+	}
+
+	/// Draws the frame with `scale` pixels per cell, highlighted cells get the colour of their highlight
+	pub fn to_image(&self, scale: usize, color: impl Fn(&str) -> Rgb) -> Image {
+		let cells: Vec<Vec<Rgb>> = self
+			.snapshot
+			.rows
+			.iter()
+			.enumerate()
+			.map(|(y, row)| {
+				row.iter()
+					.enumerate()
+					.map(|(x, cell)| self.highlight_at(y, x).map_or_else(|| color(cell), color_rgb))
+					.collect()
+			})
+			.collect();
+		Image::from_cells(&cells, scale)
+	}
+
+	pub fn render(&self) -> String {
+		let mut output = String::new();
+		if let Some(caption) = &self.caption {
+			output.push_str(&format!("{}\n", caption.bold()));
+		}
+		for (y, row) in self.snapshot.rows.iter().enumerate() {
+			for (x, cell) in row.iter().enumerate() {
+				match self.highlight_at(y, x) {
+					Some(color) => output.push_str(&cell.color(color).bold().to_string()),
+					None => output.push_str(cell),
+				}
+			}
+			output.push('\n');
+		}
+		output
+	}
+}
+
+/// Colour of a cell in an image, empty cells (`.`) blend in with the background
+pub fn cell_color(cell: &str) -> Rgb {
+	match cell {
+		"." | " " => BACKGROUND,
+		_ => [204, 204, 204],
+	}
+}
+
+fn color_rgb(color: Color) -> Rgb {
+	match color {
+		Color::Black => [0, 0, 0],
+		Color::Red => [205, 49, 49],
+		Color::Green => [0, 153, 0],
+		Color::Yellow => [229, 229, 16],
+		Color::Blue => [36, 114, 200],
+		Color::Magenta => [188, 63, 188],
+		Color::Cyan => [17, 168, 205],
+		Color::White => [229, 229, 229],
+		Color::BrightBlack => [102, 102, 102],
+		Color::BrightRed => [241, 76, 76],
+		Color::BrightGreen => [35, 209, 139],
+		Color::BrightYellow => [255, 255, 102],
+		Color::BrightBlue => [59, 142, 234],
+		Color::BrightMagenta => [214, 112, 214],
+		Color::BrightCyan => [41, 184, 219],
+		Color::BrightWhite => [255, 255, 255],
+		Color::TrueColor { r, g, b } => [r, g, b],
+	}
+}
+
+/// Plays the frames in the terminal, redrawing the screen `fps` times per second
+pub fn play(frames: &[Frame], fps: f64) {
+	let delay = Duration::from_secs_f64(1.0 / fps.max(0.1));
+	let mut stdout = io::stdout().lock();
+	for (index, frame) in frames.iter().enumerate() {
+		let _ = write!(stdout, "\x1b[2J\x1b[H{}", frame.render());
+		let _ = writeln!(
+			stdout,
+			"{}",
+			format!("Frame {}/{}", index + 1, frames.len()).bright_black()
+		);
+		let _ = stdout.flush();
+		thread::sleep(delay);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::cell::RefCell;
+
+	use super::*;
+
+	#[test]
+	pub fn test_render() {
+		let mut grid: Grid<isize, isize, char> = Grid::default();
+		grid.set((-1, 2), RefCell::new('a'));
+		grid.set((0, 3), RefCell::new('b'));
+
+		let snapshot = Arc::new(Snapshot::from(&grid));
+		assert_eq!(Frame::new(&snapshot).render(), "a.\n.b\n");
+		assert!(Frame::new(&snapshot)
+			.highlight((0, 3), Color::Yellow)
+			.render()
+			.starts_with("a.\n."));
+	}
+}